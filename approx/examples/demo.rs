@@ -0,0 +1,191 @@
+/*
+ * Written By: Alex Jackson (asj18a@acu.edu)
+ *
+ * Demonstrates the approx library on f(x) = x^3 - 4x^2 + 3x + 1.
+ * See examples/homework.rs for the full comparison of every method.
+ *
+ * Running the program:
+ *  - Ensure you have the latest rust toolchain
+ *  - Run the following command: cargo run --example demo
+ *  - Or print the roots as JSON or CSV: cargo run --example demo -- --format json
+ *
+ *  */
+
+use approx::*;
+use clap::{Parser, ValueEnum};
+use expr::Expression;
+use serde::Serialize;
+
+fn f<T: Scalar>(x: T) -> T {
+    x.powi(3) - x.powi(2) * 4.0 + x * 3.0 + 1.0
+}
+
+fn ff(x: f64) -> f64 {
+    3.0 * x.powf(2.0) - 8.0 * x + 3.0
+}
+
+fn fff(x: f64) -> f64 {
+    6.0 * x - 8.0
+}
+
+// f and its derivative in any floating-point type
+fn f_real<R: Real>(x: R) -> R {
+    x.powi(3) - R::from_f64(4.0) * x.powi(2) + R::from_f64(3.0) * x + R::from_f64(1.0)
+}
+
+fn ff_real<R: Real>(x: R) -> R {
+    R::from_f64(3.0) * x.powi(2) - R::from_f64(8.0) * x + R::from_f64(3.0)
+}
+
+// The options of the program
+#[derive(Parser)]
+#[command(about = "Approximates a root of x^3 - 4x^2 + 3x + 1 with every method")]
+struct Cli {
+    /// Print the results as JSON or CSV for other programs to read, instead of as text
+    #[arg(long, value_enum)]
+    format: Option<Format>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Json,
+    Csv,
+}
+
+// A method's root, or the reason it failed, for JSON output
+#[derive(Serialize)]
+struct Outcome<'a> {
+    method: &'a str,
+    #[serde(flatten)]
+    root: Option<&'a Root>,
+    error: Option<String>,
+}
+
+fn main() {
+    let cli: Cli = Cli::parse();
+    let opts = SolverOptions::default();
+    let results = [
+        ("Bisection Method", bisection(f, (-1.0, 0.0), &opts)),
+        ("Newton's Method", newton(f, ff, -1.0, &opts)),
+        ("Newton's Method (autodiff)", newton_ad(f, -1.0, &opts)),
+        ("Halley's Method", halley(f, ff, fff, -1.0, &opts)),
+        ("Halley's Method (autodiff)", halley_ad(f, -1.0, &opts)),
+        ("Secant Method", secant(f, (-1.0, 0.0), &opts)),
+        ("Steffensen's Method", steffensen(f, -0.5, &opts)),
+        ("Muller's Method", muller(f, (-1.0, -0.5, 0.0), &opts)),
+        ("Inverse Quadratic Iteration", invquad(f, (-1.0, -0.5, 0.0), &opts)),
+        ("False Position Method", falsepos(f, (-1.0, 0.0), &opts)),
+        ("Illinois Method", illinois(f, (-1.0, 0.0), &opts)),
+        ("Pegasus Method", pegasus(f, (-1.0, 0.0), &opts)),
+        ("Anderson-Björck Method", anderson_bjorck(f, (-1.0, 0.0), &opts)),
+        ("Ridders' Method", ridders(f, (-1.0, 0.0), &opts)),
+        ("Brent's Method", brent(f, (-1.0, 0.0), &opts)),
+        ("Brent's Method from x0 = 3.5", bracket_and_solve(f, (3.5, 3.5), brent, &opts)),
+    ];
+
+    // With --format json or --format csv, print the roots for other programs to read
+    match cli.format {
+        Some(Format::Json) => {
+            let outcomes: Vec<Outcome> = results.iter().map(|(method, result)| Outcome { method, root: result.as_ref().ok(), error: result.as_ref().err().map(|e| e.to_string()) }).collect();
+
+            println!("{}", serde_json::to_string_pretty(&outcomes).expect("results can always be written as JSON"));
+            return;
+        },
+        Some(Format::Csv) => {
+            println!("method,x,iterations,f_evaluations,df_evaluations,error");
+
+            for (method, result) in &results {
+                match result {
+                    Ok(r) => println!("{},{},{},{},{},", method, r.x, r.iterations, r.f_evaluations, r.df_evaluations),
+                    Err(e) => println!("{},,,,,\"{}\"", method, e),
+                }
+            }
+            return;
+        },
+        None => {},
+    }
+
+    for (name, result) in results {
+        match result {
+            Ok(answer) => println!(
+                "{:<30}{:.8} ({} iterations, {} f and {} f' evaluations)",
+                name, answer.x, answer.iterations, answer.f_evaluations, answer.df_evaluations
+            ),
+            Err(e) => println!("{:<30}{}", name, e),
+        }
+    }
+
+    // Estimate the order of convergence of the open methods from the distances between iterates
+    let traced = SolverOptions { trace: true, ..opts };
+    let orders = [
+        ("Newton's Method", newton(f, ff, -1.0, &traced), 2.0),
+        ("Halley's Method", halley(f, ff, fff, -1.0, &traced), 3.0),
+        ("Secant Method", secant(f, (-1.0, 0.0), &traced), 1.618),
+        ("Steffensen's Method", steffensen(f, -0.5, &traced), 2.0),
+        ("Muller's Method", muller(f, (-1.0, -0.5, 0.0), &traced), 1.839),
+        ("Inverse Quadratic Iteration", invquad(f, (-1.0, -0.5, 0.0), &traced), 1.839),
+    ];
+
+    for (name, result, expected) in orders {
+        match result.ok().and_then(|answer| answer.trace?.convergence(None)) {
+            Some(c) => println!("{:<30}order {:.2} (expected {}), rate {:.4}", name, c.order, expected, c.rate),
+            None => println!("{:<30}order could not be estimated", name),
+        }
+    }
+
+    // Solve in f32 for speed, with tolerances it can reach, and in double-double precision to check the f64 root
+    let single = SolverOptions { x_abs_tol: 1e-6, f_tol: 1e-6, ..opts };
+    let double_double = SolverOptions { x_abs_tol: 1e-30, f_tol: 1e-30, ..opts };
+
+    match newton(f_real::<f32>, ff_real::<f32>, -1.0, &single) {
+        Ok(answer) => println!("{:<30}{:.8} ({} iterations)", "Newton's Method (f32)", answer.x, answer.iterations),
+        Err(e) => println!("{:<30}{}", "Newton's Method (f32)", e),
+    }
+
+    match newton(f_real::<DoubleDouble>, ff_real::<DoubleDouble>, DoubleDouble::from(-1.0), &double_double) {
+        Ok(answer) => println!("{:<30}{:.30} ({} iterations)", "Newton's Method (32 digits)", answer.x, answer.iterations),
+        Err(e) => println!("{:<30}{}", "Newton's Method (32 digits)", e),
+    }
+
+    // The same function, parsed from a string at runtime, with its derivatives found symbolically
+    let expression = Expression::parse("x^3 - 4x^2 + 3x + 1", &["x"]).expect("the function is a valid expression");
+    let derivative = expression.derivative("x");
+    let second_derivative = derivative.derivative("x");
+
+    println!("{:<30}{}", "f'(x) =", derivative);
+    println!("{:<30}{}", "f''(x) =", second_derivative);
+
+    let (parsed, dparsed, ddparsed) = (expression.compile(), derivative.compile(), second_derivative.compile());
+    let (parsed, dparsed, ddparsed) = (parsed.bind().expect("f is in x"), dparsed.bind().expect("f' is in x"), ddparsed.bind().expect("f'' is in x"));
+    let (p, dp, ddp) = (|x: f64| parsed([x]), |x: f64| dparsed([x]), |x: f64| ddparsed([x]));
+    let symbolic = [
+        ("Brent's Method (parsed)", brent(p, (-1.0, 0.0), &opts)),
+        ("Newton's Method (symbolic)", newton(p, dp, -1.0, &opts)),
+        ("Halley's Method (symbolic)", halley(p, dp, ddp, -1.0, &opts)),
+    ];
+
+    for (name, result) in symbolic {
+        match result {
+            Ok(answer) => println!("{:<30}{:.8} ({} iterations)", name, answer.x, answer.iterations),
+            Err(e) => println!("{:<30}{}", name, e),
+        }
+    }
+
+    match Polynomial::new(vec![1.0, 3.0, -4.0, 1.0]).roots(&opts) {
+        Ok(roots) => {
+            for r in roots {
+                println!("{:<30}{:.8} (multiplicity {})", "Aberth-Ehrlich Method", r.root, r.multiplicity);
+            }
+        },
+        Err(e) => println!("{:<30}{}", "Aberth-Ehrlich Method", e),
+    }
+
+    match all_roots(f, -5.0, 5.0, &opts) {
+        Ok(roots) => {
+            for r in roots {
+                println!("{:<30}{:.8} (in [{}, {}])", "All roots on [-5, 5]", r.x, r.bracket.0, r.bracket.1);
+            }
+        },
+        Err(e) => println!("{:<30}{}", "All roots on [-5, 5]", e),
+    }
+}
@@ -0,0 +1,11 @@
+use approx::Scalar;
+
+// Function 1
+pub fn f<T: Scalar>(x: T) -> T {
+    x.powi(3) - x.powi(2) * 4.0 + x * 3.0 + 1.0
+}
+
+// Function 2
+pub fn g<T: Scalar>(x: T) -> T {
+    x.exp() * (-x.cos() * 2.0 + 1.0)
+}
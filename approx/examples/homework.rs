@@ -0,0 +1,67 @@
+/*
+ * Runs each approximation method on the hard-coded homework functions.
+ *
+ * Running the example:
+ *  - Run the following command: cargo run --example homework
+ *  - The same problems can be run without editing any source with the numeth batch subcommand:
+ *    cargo run -p numeth -- batch numeth/jobs/homework.toml
+ *
+ *  */
+
+mod functions;
+
+use approx::*;
+use crate::functions::*;
+
+// A homework function, and where each method starts looking for its root
+struct Problem {
+    f: fn(f64) -> f64,
+    // The same function in dual numbers, for Newton's method
+    f_dual: fn(Dual) -> Dual,
+    interval: (f64, f64),
+    x0: f64,
+    secant: (f64, f64),
+    muller: (f64, f64, f64),
+}
+
+// Print a method's root, where it started and how many iterations it took
+fn report(method: &str, start: String, result: Result<Root, RootError>) {
+    print!("{}", method);
+
+    match result {
+        Ok(answer) => {
+            println!("{0:.8}", answer.x);
+            println!(" - {}", start);
+            println!(" - Iterations: {}", answer.iterations);
+        },
+        Err(e) => println!("{}", e),
+    }
+}
+
+fn main() {
+    let opts = SolverOptions::default();
+
+    let problems = [
+        //-0.247
+        Problem { f, f_dual: f, interval: (-1.0, 0.0), x0: -1.0, secant: (-1.0, 0.0), muller: (-1.0, -0.5, 0.0) },
+        //2.802
+        Problem { f, f_dual: f, interval: (2.0, 3.0), x0: 3.0, secant: (3.0, 2.0), muller: (3.0, 2.5, 2.0) },
+        // -1.0471975542604923248291015625
+        Problem { f: g, f_dual: g, interval: (-1.25, -0.75), x0: -1.75, secant: (-1.25, -0.75), muller: (-1.25, -1.0, -0.75) },
+    ];
+
+    for (i, p) in problems.iter().enumerate() {
+        println!("\nFunction {}: \n", i + 1);
+
+        let (a, b) = p.interval;
+        let (x0, x1) = p.secant;
+        let (y0, y1, y2) = p.muller;
+
+        report("Bisection Method: \t\t", format!("[a, b] = [{}, {}]", a, b), bisection(p.f, p.interval, &opts));
+        report("Newton's Method: \t\t", format!("x0 = {}", p.x0), newton_ad(p.f_dual, p.x0, &opts));
+        report("Secant Method: \t\t\t", format!("(x0, x1) = [{}, {}]", x0, x1), secant(p.f, p.secant, &opts));
+        report("Muller's Method: \t\t", format!("(x0, x1, x2) = [{}, {}, {}]", y0, y1, y2), muller(p.f, p.muller, &opts));
+        report("Inverse Quadratic Iteration: \t", format!("(x0, x1, x2) = [{}, {}, {}]", y0, y1, y2), invquad(p.f, p.muller, &opts));
+        report("False Position Method: \t\t", format!("(x0, x1) = [{}, {}]", a, b), falsepos(p.f, p.interval, &opts));
+    }
+}
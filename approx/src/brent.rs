@@ -0,0 +1,162 @@
+use crate::{Real, Root, RootError, Solver, SolverOptions};
+
+/// Brent's method on the bracket `x = (a, b)`.
+///
+/// Combines bisection, the secant method and inverse quadratic interpolation:
+/// an interpolation step is only accepted when it lands well inside the
+/// bracket and shrinks it fast enough, otherwise the method falls back to
+/// bisection. Like [`bisection`](crate::bisection) it always converges when
+/// `f(a)` and `f(b)` have opposite signs, but usually as fast as
+/// [`invquad`](crate::invquad). Fails with [`RootError::Discontinuity`] if
+/// the bracket shrinks to within tolerance while `|f|` is still as large as
+/// at both of its ends, as it is around a pole or a jump.
+pub fn brent<R: Real>(mut f: impl FnMut(R) -> R, x: (R, R), opts: &SolverOptions) -> Result<Root<R>, RootError<R>> {
+    let s = Solver::new(opts);
+    let (zero, half, one, two, three): (R, R, R, R, R) = (R::from_f64(0.0), R::from_f64(0.5), R::from_f64(1.0), R::from_f64(2.0), R::from_f64(3.0));
+    let (mut a, mut b) = x;
+    let mut fa: R = s.eval(&mut f, a);
+    let mut fb: R = s.eval(&mut f, b);
+
+    // Ensure signs are not the same
+    if let Some(root) = s.bracket(a, fa, b, fb)? {
+        return Ok(s.finish(root, 0));
+    }
+
+    // The largest finite |f| at the ends of the bracket, which |f| near a root of a continuous f falls well below
+    let finite = |y: R| if y.is_finite() { y.abs() } else { zero };
+    let f_ends: R = finite(fa).max(finite(fb));
+
+    // c is the contrapoint: the root is always between b and c
+    let (mut c, mut fc) = (b, fb);
+
+    // d is the current step, e the step before it
+    let mut d: R = b - a;
+    let mut e: R = d;
+    let mut i: usize = 0;
+
+    loop {
+        // Keep the root bracketed between b and c
+        if (fb > zero) == (fc > zero) {
+            c = a;
+            fc = fa;
+            d = b - a;
+            e = d;
+        }
+
+        // Make b the best approximation so far
+        if fc.abs() < fb.abs() {
+            (a, fa) = (b, fb);
+            (b, fb) = (c, fc);
+            (c, fc) = (a, fa);
+        }
+
+        let tol: R = two * R::epsilon() * b.abs() + half * (R::from_f64(opts.x_abs_tol) + R::from_f64(opts.x_rel_tol) * b.abs());
+        let m: R = half * (c - b);
+
+        s.record(&b, &fb, Some((b.min(c), b.max(c))));
+
+        if fb == zero || (m.abs() <= tol && fb.abs() <= R::from_f64(opts.f_tol)) {
+            return Ok(s.finish(b, i));
+        }
+
+        if m.abs() <= tol && fb.abs() >= f_ends {
+            return Err(RootError::Discontinuity { x: b, fx: fb });
+        }
+
+        if let Some(err) = s.limit(i, b) {
+            return Err(err);
+        }
+
+        if m.abs() <= tol {
+            // The bracket is within tolerance but |f| is not yet, so keep bisecting inside it
+            d = m;
+            e = d;
+        } else if e.abs() >= tol && fa.abs() > fb.abs() {
+            let r: R = fb / fa;
+
+            // Secant step if only two distinct points are known, otherwise inverse quadratic interpolation
+            let (mut p, mut q) = if a == c {
+                (two * m * r, one - r)
+            } else {
+                let q: R = fa / fc;
+                let t: R = fb / fc;
+
+                (r * (two * m * q * (q - t) - (b - a) * (t - one)), (q - one) * (t - one) * (r - one))
+            };
+
+            if p > zero {
+                q = -q;
+            }
+
+            p = p.abs();
+
+            // Accept the interpolation only if it stays in the bracket and converges quickly enough
+            if two * p < (three * m * q - (tol * q).abs()).min((e * q).abs()) {
+                e = d;
+                d = p / q;
+            } else {
+                d = m;
+                e = d;
+            }
+        } else {
+            d = m;
+            e = d;
+        }
+
+        (a, fa) = (b, fb);
+        b += if d.abs() > tol || m.abs() <= tol { d } else { tol.copysign(m) };
+        fb = s.eval(&mut f, b);
+
+        if !fb.is_finite() {
+            return Err(RootError::NonFinite);
+        }
+
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_root() {
+        let root: Root = brent(|x: f64| x * x - 2.0, (0.0, 2.0), &SolverOptions::default()).unwrap();
+
+        assert!((root.x - std::f64::consts::SQRT_2).abs() < 1e-8);
+    }
+
+    #[test]
+    fn reversed_bracket() {
+        let opts: SolverOptions = SolverOptions::default();
+        let forward: Root = brent(|x: f64| x.cos() - x, (0.0, 1.0), &opts).unwrap();
+        let reversed: Root = brent(|x: f64| x.cos() - x, (1.0, 0.0), &opts).unwrap();
+
+        assert!((forward.x - 0.7390851332151607).abs() < 1e-8);
+        assert!((reversed.x - forward.x).abs() < 1e-8);
+    }
+
+    #[test]
+    fn f_tol_tighter_than_x_tol() {
+        let opts: SolverOptions = SolverOptions { x_abs_tol: 0.1, f_tol: 1e-12, ..SolverOptions::default() };
+        let root: Root = brent(|x: f64| x * x - 2.0, (1.4, 1.45), &opts).unwrap();
+
+        assert!((root.x * root.x - 2.0).abs() <= 1e-12);
+    }
+
+    #[test]
+    fn pole_is_discontinuity() {
+        // tan changes sign across its pole at pi / 2 without having a root there
+        match brent(|x: f64| x.tan(), (1.0, 2.0), &SolverOptions::default()) {
+            Err(RootError::Discontinuity { x, .. }) => assert!((x - std::f64::consts::FRAC_PI_2).abs() < 1e-8),
+            result => panic!("expected a discontinuity, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn jump_is_discontinuity() {
+        let result = brent(|x: f64| if x < 0.5 { -1.0 } else { 1.0 }, (0.0, 1.0), &SolverOptions::default());
+
+        assert!(matches!(result, Err(RootError::Discontinuity { .. })), "{:?}", result);
+    }
+}
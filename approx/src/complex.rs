@@ -0,0 +1,45 @@
+use num_complex::Complex;
+
+use crate::{DividedDifferences, Root, RootError, Solver, SolverOptions};
+
+/// Muller's method in the complex plane, starting from the three points `x = (x0, x1, x2)`.
+///
+/// Unlike [`muller`](crate::muller), a negative discriminant does not stop
+/// the method: the iterates simply become complex, so it can find complex
+/// roots even when started from real points. The tolerances in `opts` are
+/// applied to the modulus of each step and of `f`.
+pub fn muller_complex(mut g: impl FnMut(Complex<f64>) -> Complex<f64>, mut x: (Complex<f64>, Complex<f64>, Complex<f64>), opts: &SolverOptions) -> Result<Root<Complex<f64>>, RootError<Complex<f64>>> {
+    let s = Solver::new(opts);
+    let mut f = |x: Complex<f64>| s.eval(&mut g, x);
+    let mut fx: (Complex<f64>, Complex<f64>, Complex<f64>) = (f(x.0), f(x.1), f(x.2));
+    let mut i: usize = 0;
+
+    loop {
+        let table = DividedDifferences::from_values(&[x.2, x.1, x.0], &[fx.2, fx.1, fx.0]);
+        let w: Complex<f64> = table.get(0, 1) + (x.2 - x.1) * table.get(0, 2);
+        let d: Complex<f64> = (w * w - 4.0 * fx.2 * table.get(0, 2)).sqrt();
+
+        // Choose the sign that gives the larger denominator
+        let x3: Complex<f64> = x.2 - 2.0 * fx.2 / (if (w - d).norm() > (w + d).norm() { w - d } else { w + d });
+
+        if !x3.is_finite() {
+            return Err(RootError::NonFinite);
+        }
+
+        let fx3: Complex<f64> = f(x3);
+
+        s.record(&x3, &fx3, None);
+
+        if s.converged((x3 - x.2).norm(), x3.norm(), fx3.norm()) {
+            return Ok(s.finish(x3, i));
+        }
+
+        if let Some(e) = s.limit(i, x3) {
+            return Err(e);
+        }
+
+        x = (x.1, x.2, x3);
+        fx = (fx.1, fx.2, fx3);
+        i += 1;
+    }
+}
@@ -0,0 +1,127 @@
+use std::ops::{Div, Sub};
+
+/// A table of the divided differences of a function over a list of nodes.
+///
+/// Built once in `O(n^2)` operations, with the function evaluated once per
+/// node, after which any divided difference `f[x_i, ..., x_j]` of
+/// consecutive nodes can be looked up. Works for any number type, including
+/// [`Complex`](crate::Complex). The nodes must be distinct.
+///
+/// ```
+/// use approx::DividedDifferences;
+///
+/// let table = DividedDifferences::new(|x: f64| x * x, &[0.0, 1.0, 3.0]);
+///
+/// // The second divided difference of x^2 is its leading coefficient
+/// assert_eq!(table.get(0, 2), 1.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DividedDifferences<T = f64> {
+    nodes: Vec<T>,
+    // table[k][i] = f[x_i, ..., x_(i + k)]
+    table: Vec<Vec<T>>,
+}
+
+impl<T: Copy + Sub<Output = T> + Div<Output = T>> DividedDifferences<T> {
+    /// Builds the table of `f` over the nodes `x`.
+    pub fn new(mut f: impl FnMut(T) -> T, x: &[T]) -> Self {
+        let y: Vec<T> = x.iter().map(|x| f(*x)).collect();
+
+        Self::from_values(x, &y)
+    }
+
+    /// Builds the table from the nodes `x` and the values `y[i] = f(x[i])`,
+    /// for when `f` has already been evaluated.
+    pub fn from_values(x: &[T], y: &[T]) -> Self {
+        assert_eq!(x.len(), y.len(), "each node needs exactly one value");
+
+        let mut table: Vec<Vec<T>> = vec![y.to_vec()];
+
+        for k in 1..x.len() {
+            let last: &Vec<T> = &table[k - 1];
+            let next: Vec<T> = (0..x.len() - k).map(|i| (last[i + 1] - last[i]) / (x[i + k] - x[i])).collect();
+
+            table.push(next);
+        }
+
+        DividedDifferences { nodes: x.to_vec(), table }
+    }
+
+    /// The nodes the table was built over.
+    pub fn nodes(&self) -> &[T] {
+        &self.nodes
+    }
+
+    /// The divided difference `f[x_i, ..., x_j]`, where `i <= j`.
+    pub fn get(&self, i: usize, j: usize) -> T {
+        assert!(i <= j && j < self.nodes.len(), "f[x_{}, ..., x_{}] is not in the table", i, j);
+
+        self.table[j - i][i]
+    }
+
+    /// The coefficients `f[x_0], f[x_0, x_1], ..., f[x_0, ..., x_n]` of the
+    /// Newton form of the interpolating polynomial.
+    pub fn coefficients(&self) -> Vec<T> {
+        self.table.iter().filter_map(|column| column.first().copied()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::Complex;
+
+    fn cubic(x: f64) -> f64 {
+        2.0 * x.powi(3) - x + 5.0
+    }
+
+    #[test]
+    fn differences_of_a_cubic() {
+        let x: [f64; 5] = [-1.0, 0.0, 0.5, 2.0, 3.0];
+        let table = DividedDifferences::new(cubic, &x);
+
+        for i in 0..4 {
+            assert_eq!(table.get(i, i + 1), (cubic(x[i + 1]) - cubic(x[i])) / (x[i + 1] - x[i]));
+        }
+
+        // The third divided difference of a cubic is its leading coefficient, and the fourth vanishes
+        for i in 0..2 {
+            assert!((table.get(i, i + 3) - 2.0).abs() < 1e-12);
+        }
+
+        assert!(table.get(0, 4).abs() < 1e-12);
+        assert_eq!(table, DividedDifferences::from_values(&x, &x.map(cubic)));
+    }
+
+    #[test]
+    fn newton_form_interpolates() {
+        let x: [f64; 4] = [0.0, 1.0, 2.5, 4.0];
+        let table = DividedDifferences::new(|x: f64| x.sin(), &x);
+        let c: Vec<f64> = table.coefficients();
+
+        assert_eq!(c.len(), x.len());
+
+        // p(t) = c0 + c1 (t - x0) + c2 (t - x0)(t - x1) + ..., evaluated by Horner's rule
+        for t in x {
+            let p: f64 = (0..c.len()).rev().fold(0.0, |p, k| p * (t - x[k]) + c[k]);
+
+            assert!((p - t.sin()).abs() < 1e-12, "p({}) = {}", t, p);
+        }
+    }
+
+    #[test]
+    fn complex_nodes() {
+        let x: [Complex<f64>; 3] = [Complex::new(0.0, 0.0), Complex::new(1.0, 1.0), Complex::new(-1.0, 2.0)];
+        let table = DividedDifferences::new(|z: Complex<f64>| z * z, &x);
+
+        assert!((table.get(0, 2) - Complex::new(1.0, 0.0)).norm() < 1e-12);
+        assert!((table.get(0, 1) - (x[0] + x[1])).norm() < 1e-12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn reversed_indices() {
+        DividedDifferences::new(cubic, &[0.0, 1.0, 2.0]).get(2, 1);
+    }
+}
@@ -0,0 +1,419 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::{iterate, Real, Root, RootError, Solver, SolverOptions};
+
+/// A [`Real`] type that can also be combined with `f64` constants, so a
+/// function can be written once and evaluated on both `f64` and [`Dual`]
+/// numbers.
+///
+/// Constants have to be on the right of an operator or converted with
+/// `T::from_f64`, so `x^3 - 4x^2 + 3x + 1` is written:
+///
+/// ```
+/// use approx::Scalar;
+///
+/// fn f<T: Scalar>(x: T) -> T {
+///     x.powi(3) - x.powi(2) * 4.0 + x * 3.0 + 1.0
+/// }
+/// ```
+pub trait Scalar: Real + Add<f64, Output = Self> + Sub<f64, Output = Self> + Mul<f64, Output = Self> + Div<f64, Output = Self> {}
+
+impl<T> Scalar for T where T: Real + Add<f64, Output = T> + Sub<f64, Output = T> + Mul<f64, Output = T> + Div<f64, Output = T> {}
+
+/// A dual number `re + eps ε`, where `ε² = 0`.
+///
+/// Evaluating a function at `x + ε` gives `f(x) + f'(x) ε`, so the
+/// derivative is computed exactly alongside the value. Nesting dual numbers,
+/// `Dual<Dual<f64>>`, gives second derivatives as well.
+///
+/// Dual numbers implement [`Real`], carrying the derivative through every
+/// operation, where `floor`, `round` and `signum` have a derivative of 0.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dual<T = f64> {
+    pub re: T,
+    pub eps: T,
+}
+
+impl<T> Dual<T> {
+    pub fn new(re: T, eps: T) -> Self {
+        Dual { re, eps }
+    }
+}
+
+impl<T: Scalar> Dual<T> {
+    /// The dual number `x + ε`, for differentiating with respect to `x`.
+    pub fn variable(x: T) -> Self {
+        Dual { re: x, eps: T::from_f64(1.0) }
+    }
+
+    // The constant x, whose derivative is zero
+    fn constant(x: T) -> Self {
+        Dual { re: x, eps: T::from_f64(0.0) }
+    }
+
+    // Apply a function with value fx and derivative dfx at self.re, using the chain rule
+    fn chain(self, fx: T, dfx: T) -> Self {
+        Dual { re: fx, eps: dfx * self.eps }
+    }
+}
+
+impl<T: Scalar> From<f64> for Dual<T> {
+    fn from(x: f64) -> Self {
+        Dual::constant(T::from_f64(x))
+    }
+}
+
+// Dual numbers are ordered by their values, and then by their derivatives
+impl<T: Scalar> PartialOrd for Dual<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.re.partial_cmp(&other.re)? {
+            Ordering::Equal => self.eps.partial_cmp(&other.eps),
+            ordering => Some(ordering),
+        }
+    }
+}
+
+impl<T: Scalar> fmt::Display for Dual<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} + {}ε", self.re, self.eps)
+    }
+}
+
+impl<T: Scalar> Add for Dual<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Dual { re: self.re + rhs.re, eps: self.eps + rhs.eps }
+    }
+}
+
+impl<T: Scalar> Sub for Dual<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Dual { re: self.re - rhs.re, eps: self.eps - rhs.eps }
+    }
+}
+
+impl<T: Scalar> Mul for Dual<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Dual { re: self.re * rhs.re, eps: self.re * rhs.eps + self.eps * rhs.re }
+    }
+}
+
+impl<T: Scalar> Div for Dual<T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Dual { re: self.re / rhs.re, eps: (self.eps * rhs.re - self.re * rhs.eps) / (rhs.re * rhs.re) }
+    }
+}
+
+impl<T: Scalar> Neg for Dual<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Dual { re: -self.re, eps: -self.eps }
+    }
+}
+
+impl<T: Scalar> Add<f64> for Dual<T> {
+    type Output = Self;
+
+    fn add(self, rhs: f64) -> Self {
+        Dual { re: self.re + rhs, eps: self.eps }
+    }
+}
+
+impl<T: Scalar> Sub<f64> for Dual<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: f64) -> Self {
+        Dual { re: self.re - rhs, eps: self.eps }
+    }
+}
+
+impl<T: Scalar> Mul<f64> for Dual<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self {
+        Dual { re: self.re * rhs, eps: self.eps * rhs }
+    }
+}
+
+impl<T: Scalar> Div<f64> for Dual<T> {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self {
+        Dual { re: self.re / rhs, eps: self.eps / rhs }
+    }
+}
+
+impl<T: Scalar> AddAssign for Dual<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Scalar> SubAssign for Dual<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Scalar> MulAssign for Dual<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Scalar> DivAssign for Dual<T> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<T: Scalar> Real for Dual<T> {
+    fn from_f64(x: f64) -> Self {
+        Dual::from(x)
+    }
+
+    fn to_f64(self) -> f64 {
+        self.re.to_f64()
+    }
+
+    fn epsilon() -> Self {
+        Dual::constant(T::epsilon())
+    }
+
+    fn max_value() -> Self {
+        Dual::constant(T::max_value())
+    }
+
+    fn pi() -> Self {
+        Dual::constant(T::pi())
+    }
+
+    /// Takes the derivative of `|x|` at 0 to be 0, a subgradient, rather than
+    /// leaving it undefined.
+    fn abs(self) -> Self {
+        let zero: T = T::from_f64(0.0);
+
+        self.chain(self.re.abs(), if self.re == zero { zero } else { self.re.signum() })
+    }
+
+    fn signum(self) -> Self {
+        Dual::constant(self.re.signum())
+    }
+
+    fn copysign(self, sign: Self) -> Self {
+        if self.re.copysign(sign.re) == self.re { self } else { -self }
+    }
+
+    fn max(self, other: Self) -> Self {
+        if self.re.is_nan() || other.re > self.re { other } else { self }
+    }
+
+    fn min(self, other: Self) -> Self {
+        if self.re.is_nan() || other.re < self.re { other } else { self }
+    }
+
+    fn floor(self) -> Self {
+        Dual::constant(self.re.floor())
+    }
+
+    fn round(self) -> Self {
+        Dual::constant(self.re.round())
+    }
+
+    fn sqrt(self) -> Self {
+        let root: T = self.re.sqrt();
+
+        self.chain(root, T::from_f64(0.5) / root)
+    }
+
+    fn powi(self, n: i32) -> Self {
+        // x^0 is constant, where n x^(n - 1) would be 0 * inf at x = 0
+        if n == 0 {
+            return Self::from_f64(1.0);
+        }
+
+        self.chain(self.re.powi(n), self.re.powi(n - 1) * n as f64)
+    }
+
+    fn powf(self, n: Self) -> Self {
+        let value: T = self.re.powf(n.re);
+        let zero: T = T::from_f64(0.0);
+
+        // (a^b)' = b a^(b - 1) a' + a^b ln(a) b', leaving out the second term for a constant
+        // exponent, where ln(a) may not be defined
+        let base: T = n.re * self.re.powf(n.re - T::from_f64(1.0)) * self.eps;
+        let exponent: T = if n.eps == zero { zero } else { value * self.re.ln() * n.eps };
+
+        Dual { re: value, eps: base + exponent }
+    }
+
+    fn exp(self) -> Self {
+        let e: T = self.re.exp();
+
+        self.chain(e, e)
+    }
+
+    fn ln(self) -> Self {
+        self.chain(self.re.ln(), T::from_f64(1.0) / self.re)
+    }
+
+    fn sin(self) -> Self {
+        self.chain(self.re.sin(), self.re.cos())
+    }
+
+    fn cos(self) -> Self {
+        self.chain(self.re.cos(), -self.re.sin())
+    }
+
+    fn tan(self) -> Self {
+        let t: T = self.re.tan();
+
+        self.chain(t, t * t + 1.0)
+    }
+
+    fn is_finite(self) -> bool {
+        self.re.is_finite() && self.eps.is_finite()
+    }
+
+    fn is_nan(self) -> bool {
+        self.re.is_nan() || self.eps.is_nan()
+    }
+}
+
+/// The value and derivative of `f` at `x`, computed with dual numbers.
+pub fn derivative<R: Scalar>(mut f: impl FnMut(Dual<R>) -> Dual<R>, x: R) -> (R, R) {
+    let y: Dual<R> = f(Dual::variable(x));
+
+    (y.re, y.eps)
+}
+
+/// Newton's method for `f` starting from `x`, with the derivative computed
+/// automatically with dual numbers.
+///
+/// Write `f` once as a function generic over [`Scalar`] and pass it
+/// directly; no hand-derived derivative is needed. Each evaluation on dual
+/// numbers gives `f` and its derivative together, and is counted in
+/// [`Root::f_evaluations`].
+///
+/// ```
+/// use approx::{newton_ad, Scalar, SolverOptions};
+///
+/// fn g<T: Scalar>(x: T) -> T {
+///     x.exp() * (-x.cos() * 2.0 + 1.0)
+/// }
+///
+/// let root = newton_ad(g, -1.25, &SolverOptions::default()).unwrap();
+///
+/// assert!((root.x + std::f64::consts::FRAC_PI_3).abs() < 1e-8);
+/// ```
+pub fn newton_ad<R: Scalar>(mut f: impl FnMut(Dual<R>) -> Dual<R>, x: R, opts: &SolverOptions) -> Result<Root<R>, RootError<R>> {
+    let s = Solver::new(opts);
+
+    iterate(&s, x, |x| {
+        let y: Dual<R> = s.eval(&mut f, Dual::variable(x));
+
+        (y.re, y.eps, x - y.re / y.eps)
+    })
+}
+
+/// Halley's method for `f` starting from `x`, with the first and second
+/// derivatives computed automatically with nested dual numbers.
+///
+/// Like [`newton_ad`], `f` should be written once as a function generic over [`Scalar`].
+///
+/// ```
+/// use approx::{halley_ad, Scalar, SolverOptions};
+///
+/// fn f<T: Scalar>(x: T) -> T {
+///     x.powi(3) - x.powi(2) * 4.0 + x * 3.0 + 1.0
+/// }
+///
+/// let root = halley_ad(f, -1.0, &SolverOptions::default()).unwrap();
+///
+/// assert!((root.x + 0.24697960371746706).abs() < 1e-8);
+/// ```
+pub fn halley_ad<R: Scalar>(mut f: impl FnMut(Dual<Dual<R>>) -> Dual<Dual<R>>, x: R, opts: &SolverOptions) -> Result<Root<R>, RootError<R>> {
+    let s = Solver::new(opts);
+
+    iterate(&s, x, |x| {
+        let y: Dual<Dual<R>> = s.eval(&mut f, Dual::new(Dual::variable(x), Dual::from_f64(1.0)));
+        let (fx, dfx, ddfx) = (y.re.re, y.re.eps, y.eps.eps);
+        let two: R = R::from_f64(2.0);
+
+        (fx, dfx, x - (two * fx * dfx) / (two * dfx * dfx - fx * ddfx))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn abs_has_a_zero_derivative_at_zero() {
+        assert_eq!(derivative(|x: Dual| x.abs(), 0.0), (0.0, 0.0));
+        assert_eq!(derivative(|x: Dual| x.abs(), -2.0), (2.0, -1.0));
+    }
+
+    #[test]
+    fn powers_with_dual_exponents() {
+        // (x^x)' = x^x (ln(x) + 1)
+        let (y, dy) = derivative(|x: Dual| x.powf(x), 2.0);
+
+        assert_eq!(y, 4.0);
+        assert!((dy - 4.0 * (2_f64.ln() + 1.0)).abs() < 1e-12);
+
+        // A constant exponent needs no logarithm of a negative base
+        assert_eq!(derivative(|x: Dual| x.powf(Dual::from_f64(3.0)), -2.0), (-8.0, 12.0));
+    }
+
+    #[test]
+    fn second_derivatives() {
+        let x: Dual<Dual> = Dual::new(Dual::variable(1.0), Dual::new(1.0, 0.0));
+        let y: Dual<Dual> = x.sin() * x;
+
+        // (x sin(x))'' = 2 cos(x) - x sin(x)
+        assert!((y.eps.eps - (2.0 * 1_f64.cos() - 1_f64.sin())).abs() < 1e-15);
+    }
+
+    #[test]
+    fn whole_powers_at_zero() {
+        assert_eq!(derivative(|x: Dual| x.powi(0), 0.0), (1.0, 0.0));
+        assert_eq!(derivative(|x: Dual| x.powi(1), 0.0), (0.0, 1.0));
+        assert_eq!(derivative(|x: Dual| x.powi(3), 0.0), (0.0, 0.0));
+
+        let x: Dual<Dual> = Dual::new(Dual::variable(0.0), Dual::new(1.0, 0.0));
+
+        assert_eq!(x.powi(0), Dual::from_f64(1.0));
+    }
+
+    #[test]
+    fn halley_ad_matches_halley() {
+        fn f<T: Scalar>(x: T) -> T {
+            x.exp() * (-x.cos() * 2.0 + 1.0)
+        }
+
+        let opts: SolverOptions = SolverOptions::default();
+        let automatic: Root = halley_ad(f, -1.25, &opts).unwrap();
+        let by_hand: Root = crate::halley(
+            |x: f64| x.exp() * (1.0 - 2.0 * x.cos()),
+            |x: f64| x.exp() * (1.0 - 2.0 * x.cos() + 2.0 * x.sin()),
+            |x: f64| x.exp() * (1.0 + 4.0 * x.sin()),
+            -1.25,
+            &opts,
+        ).unwrap();
+
+        assert!((automatic.x + std::f64::consts::FRAC_PI_3).abs() < 1e-8);
+        assert!((automatic.x - by_hand.x).abs() < 1e-12);
+        assert_eq!(automatic.iterations, by_hand.iterations);
+    }
+}
@@ -0,0 +1,47 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// The reason a root-finding method failed.
+///
+/// `T` is the type of the approximations, which is [`Complex`](crate::Complex)
+/// for methods that search the complex plane and [`Vector`](crate::Vector)
+/// for systems of equations.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum RootError<T = f64> {
+    /// `f(a)` and `f(b)` have the same sign, so the bracket may not contain a root.
+    NoSignChange { a: T, fa: T, b: T, fb: T },
+    /// The method did not converge; `last_x` is the final approximation.
+    MaxIterations { last_x: T },
+    /// The method used up its function evaluations; `last_x` is the final approximation.
+    MaxEvaluations { last_x: T },
+    /// The derivative (or secant slope) at `x` is too close to zero to divide by,
+    /// or for systems of equations, the Jacobian at `x` is singular.
+    ZeroDerivative { x: T },
+    /// The next approximation would be a complex number.
+    ComplexStep,
+    /// An approximation or function value became infinite or NaN.
+    NonFinite,
+    /// The bracket shrank to within tolerance around `x` while `|f|` grew
+    /// rather than approaching zero, so `f` has a pole or a jump there.
+    Discontinuity { x: T, fx: T },
+    /// The method was not given the derivatives it needs.
+    MissingDerivative,
+}
+
+impl<T: fmt::Display> fmt::Display for RootError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RootError::NoSignChange { a, fa, b, fb } => write!(f, "The conditions of IVT are not met: f({}) = {} and f({}) = {} are the same sign", a, fa, b, fb),
+            RootError::MaxIterations { last_x } => write!(f, "Function did not converge, last approximation was {}", last_x),
+            RootError::MaxEvaluations { last_x } => write!(f, "Function evaluation limit reached, last approximation was {}", last_x),
+            RootError::ZeroDerivative { x } => write!(f, "Unable to approximate: the derivative at {} is too close to zero", x),
+            RootError::ComplexStep => write!(f, "The next approximation is a complex number"),
+            RootError::NonFinite => write!(f, "The approximation is not a finite number"),
+            RootError::Discontinuity { x, fx } => write!(f, "The bracket collapsed on a discontinuity at {}, where f = {}", x, fx),
+            RootError::MissingDerivative => write!(f, "The method needs the function and at least its first derivative"),
+        }
+    }
+}
+
+impl<T: fmt::Debug + fmt::Display> std::error::Error for RootError<T> {}
@@ -0,0 +1,144 @@
+use crate::{iterate, Real, Root, RootError, Solver, SolverOptions};
+
+/// Fixed-point iteration `x = g(x)` starting from `x`.
+///
+/// Converges linearly when `|g'| < 1` near the fixed point. The change
+/// `g(x) - x` is used as the residual for `opts.f_tol`.
+pub fn fixed_point<R: Real>(mut g: impl FnMut(R) -> R, x: R, opts: &SolverOptions) -> Result<Root<R>, RootError<R>> {
+    let s = Solver::new(opts);
+
+    settle(&s, x, |x| s.eval(&mut g, x))
+}
+
+/// Fixed-point iteration `x = g(x)` accelerated with Aitken's Δ² process, starting from `x`.
+///
+/// Each iteration takes two fixed-point steps and extrapolates from the
+/// three points, which converges quadratically to fixed points where plain
+/// [`fixed_point`] converges linearly.
+pub fn fixed_point_aitken<R: Real>(mut g: impl FnMut(R) -> R, x: R, opts: &SolverOptions) -> Result<Root<R>, RootError<R>> {
+    let s = Solver::new(opts);
+
+    settle(&s, x, |x| {
+        let x1: R = s.eval(&mut g, x);
+        let x2: R = s.eval(&mut g, x1);
+
+        aitken_step(x, x1, x2)
+    })
+}
+
+/// Aitken's Δ² acceleration of the sequence `x`.
+///
+/// Returns `x.len() - 2` terms, each extrapolated from three consecutive
+/// terms of `x`. Terms where the second difference is zero are passed
+/// through unchanged.
+pub fn aitken<R: Real>(x: &[R]) -> Vec<R> {
+    x.windows(3).map(|w| aitken_step(w[0], w[1], w[2])).collect()
+}
+
+/// Steffensen's method for `f` starting from `x`.
+///
+/// Replaces the derivative in Newton's method with the slope
+/// `(f(x + f(x)) - f(x)) / f(x)`, so it converges quadratically without
+/// needing `ff`. Fails with [`RootError::ZeroDerivative`] once that slope
+/// drops below `opts.min_derivative`.
+pub fn steffensen<R: Real>(mut f: impl FnMut(R) -> R, x: R, opts: &SolverOptions) -> Result<Root<R>, RootError<R>> {
+    let s = Solver::new(opts);
+
+    iterate(&s, x, |x| {
+        let fx: R = s.eval(&mut f, x);
+
+        if fx == R::from_f64(0.0) {
+            return (fx, R::from_f64(f64::INFINITY), x);
+        }
+
+        let slope: R = (s.eval(&mut f, x + fx) - fx) / fx;
+
+        (fx, slope, x - fx / slope)
+    })
+}
+
+// Extrapolate the limit of x0, x1, x2
+fn aitken_step<R: Real>(x0: R, x1: R, x2: R) -> R {
+    let d: R = x2 - R::from_f64(2.0) * x1 + x0;
+
+    if d == R::from_f64(0.0) { x2 } else { x0 - (x1 - x0).powi(2) / d }
+}
+
+// Repeatedly apply step until the approximation stops changing
+fn settle<R: Real>(s: &Solver<R>, mut x: R, mut step: impl FnMut(R) -> R) -> Result<Root<R>, RootError<R>> {
+    let mut i: usize = 0;
+
+    loop {
+        let xn: R = step(x);
+
+        s.record(&xn, &(xn - x), None);
+
+        if !xn.is_finite() {
+            return Err(RootError::NonFinite);
+        }
+
+        if s.done(xn, x, xn - x) {
+            return Ok(s.finish(xn, i));
+        }
+
+        if let Some(e) = s.limit(i, xn) {
+            return Err(e);
+        }
+
+        x = xn;
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The fixed point of cos
+    const DOTTIE: f64 = 0.7390851332151607;
+
+    #[test]
+    fn fixed_point_of_cos() {
+        let opts: SolverOptions = SolverOptions { x_abs_tol: 1e-12, f_tol: 1e-12, ..SolverOptions::default() };
+        let plain: Root = fixed_point(|x: f64| x.cos(), 1.0, &opts).unwrap();
+        let accelerated: Root = fixed_point_aitken(|x: f64| x.cos(), 1.0, &opts).unwrap();
+
+        assert!((plain.x - DOTTIE).abs() < 1e-11, "{:?}", plain);
+        assert!((accelerated.x - DOTTIE).abs() < 1e-11, "{:?}", accelerated);
+        assert!(accelerated.f_evaluations < plain.f_evaluations, "{} and {} evaluations", accelerated.f_evaluations, plain.f_evaluations);
+    }
+
+    #[test]
+    fn fixed_point_diverges() {
+        let result = fixed_point(|x: f64| 3.0 * x, 1.0, &SolverOptions::default());
+
+        assert!(matches!(result, Err(RootError::NonFinite | RootError::MaxIterations { .. })), "{:?}", result);
+    }
+
+    #[test]
+    fn aitken_sequence() {
+        // The partial sums of 4 (1 - 1/3 + 1/5 - ...) converge slowly to pi
+        let sums: Vec<f64> = (0..10).scan(0.0, |sum, k| {
+            *sum += 4.0 * (-1_f64).powi(k) / (2 * k + 1) as f64;
+            Some(*sum)
+        }).collect();
+        let accelerated: Vec<f64> = aitken(&sums);
+
+        assert_eq!(accelerated.len(), sums.len() - 2);
+        assert!((accelerated[7] - std::f64::consts::PI).abs() < 0.01 * (sums[9] - std::f64::consts::PI).abs());
+
+        // A constant sequence has no second difference, so it passes through
+        assert_eq!(aitken(&[2.0, 2.0, 2.0]), vec![2.0]);
+    }
+
+    #[test]
+    fn steffensen_converges() {
+        let root: Root = steffensen(|x: f64| x * x - 2.0, 1.5, &SolverOptions::default()).unwrap();
+
+        assert!((root.x - std::f64::consts::SQRT_2).abs() < 1e-8, "{:?}", root);
+        assert_eq!(root.df_evaluations, 0);
+
+        // An exact root is returned as it is
+        assert_eq!(steffensen(|x: f64| x - 1.0, 1.0, &SolverOptions::default()).unwrap().x, 1.0);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{iterate, Real, Root, RootError, Solver, SolverOptions};
+
+/// Halley's method for `f` with first and second derivatives `ff` and `fff`, starting from `x`.
+///
+/// Converges cubically to simple roots. Fails with
+/// [`RootError::ZeroDerivative`] once `|ff(x)|` drops below
+/// `opts.min_derivative`.
+pub fn halley<R: Real>(mut f: impl FnMut(R) -> R, mut ff: impl FnMut(R) -> R, mut fff: impl FnMut(R) -> R, x: R, opts: &SolverOptions) -> Result<Root<R>, RootError<R>> {
+    let s = Solver::new(opts);
+
+    iterate(&s, x, |x| {
+        let fx: R = s.eval(&mut f, x);
+        let dfx: R = s.eval_derivative(&mut ff, x);
+        let ddfx: R = s.eval_derivative(&mut fff, x);
+        let two: R = R::from_f64(2.0);
+
+        (fx, dfx, x - (two * fx * dfx) / (two * dfx * dfx - fx * ddfx))
+    })
+}
+
+/// Householder's method of order `d`, starting from `x`.
+///
+/// `f` holds the function followed by its first `d` derivatives, so
+/// `f = [f, ff]` is Newton's method and `f = [f, ff, fff]` is Halley's
+/// method. Converges with order `d + 1` to simple roots. Fails with
+/// [`RootError::MissingDerivative`] if `f` does not contain at least the
+/// function and its first derivative.
+pub fn householder<R: Real>(f: &[&dyn Fn(R) -> R], x: R, opts: &SolverOptions) -> Result<Root<R>, RootError<R>> {
+    if f.len() < 2 {
+        return Err(RootError::MissingDerivative);
+    }
+
+    let s = Solver::new(opts);
+    let d: usize = f.len() - 1;
+
+    iterate(&s, x, |x| {
+        let fx: Vec<R> = f.iter().enumerate().map(|(k, f)| if k == 0 { s.eval(f, x) } else { s.eval_derivative(f, x) }).collect();
+
+        if fx[0] == R::from_f64(0.0) {
+            return (fx[0], fx[1], x);
+        }
+
+        // Derivatives of 1/f, found by differentiating f * (1/f) = 1 with the Leibniz rule
+        let mut g: Vec<R> = vec![R::from_f64(1.0) / fx[0]];
+
+        for n in 1..=d {
+            let mut sum: R = R::from_f64(0.0);
+            let mut binomial: f64 = 1.0;
+
+            for k in 1..=n {
+                binomial = binomial * (n - k + 1) as f64 / k as f64;
+                sum += R::from_f64(binomial) * fx[k] * g[n - k];
+            }
+
+            g.push(-g[0] * sum);
+        }
+
+        (fx[0], fx[1], x + R::from_f64(d as f64) * g[d - 1] / g[d])
+    })
+}
+
+/// Schröder's modified Newton's method for a root of known multiplicity `m`, starting from `x`.
+///
+/// Takes Newton steps multiplied by `m`, which restores quadratic
+/// convergence at roots where Newton's method only converges linearly.
+/// `m = 1.0` is Newton's method.
+///
+/// ```
+/// use approx::{schroder, SolverOptions};
+///
+/// // From x = 3, a step of twice Newton's lands exactly on the double root of (x - 1)^2
+/// let root = schroder(|x: f64| (x - 1.0).powi(2), |x| 2.0 * (x - 1.0), 2.0, 3.0, &SolverOptions::default()).unwrap();
+///
+/// assert_eq!(root.x, 1.0);
+/// ```
+pub fn schroder<R: Real>(mut f: impl FnMut(R) -> R, mut ff: impl FnMut(R) -> R, m: R, x: R, opts: &SolverOptions) -> Result<Root<R>, RootError<R>> {
+    let s = Solver::new(opts);
+
+    iterate(&s, x, |x| {
+        let fx: R = s.eval(&mut f, x);
+        let dfx: R = s.eval_derivative(&mut ff, x);
+
+        (fx, dfx, x - m * fx / dfx)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn needs_a_derivative() {
+        let f = |x: f64| x * x - 2.0;
+
+        assert_eq!(householder(&[&f], 1.0, &SolverOptions::default()), Err(RootError::MissingDerivative));
+    }
+
+    #[test]
+    fn householder_orders() {
+        // x^3 - 4x^2 + 3x + 1 and its first three derivatives
+        let f = |x: f64| x.powi(3) - 4.0 * x.powi(2) + 3.0 * x + 1.0;
+        let ff = |x: f64| 3.0 * x.powi(2) - 8.0 * x + 3.0;
+        let fff = |x: f64| 6.0 * x - 8.0;
+        let ffff = |_: f64| 6.0;
+        let opts: SolverOptions = SolverOptions { x_abs_tol: 1e-12, f_tol: 1e-12, ..SolverOptions::default() };
+
+        let first: Root = householder(&[&f, &ff], -1.0, &opts).unwrap();
+        let second: Root = householder(&[&f, &ff, &fff], -1.0, &opts).unwrap();
+        let third: Root = householder(&[&f, &ff, &fff, &ffff], -1.0, &opts).unwrap();
+
+        for root in [&first, &second, &third] {
+            assert!((root.x + 0.24697960371746706).abs() < 1e-12, "{:?}", root);
+        }
+
+        // Order 1 and 2 are Newton's and Halley's methods, and each order takes no more iterations than the last
+        assert_eq!(first.iterations, crate::newton(f, ff, -1.0, &opts).unwrap().iterations);
+        assert_eq!(second.iterations, halley(f, ff, fff, -1.0, &opts).unwrap().iterations);
+        assert!(third.iterations <= second.iterations && second.iterations < first.iterations, "{} {} {}", first.iterations, second.iterations, third.iterations);
+    }
+
+    #[test]
+    fn schroder_multiple_root() {
+        // (x - 1)^3 (x + 2) has a triple root at 1, where Newton's method only converges linearly
+        let f = |x: f64| (x - 1.0).powi(3) * (x + 2.0);
+        let ff = |x: f64| 3.0 * (x - 1.0).powi(2) * (x + 2.0) + (x - 1.0).powi(3);
+        let opts: SolverOptions = SolverOptions { x_abs_tol: 1e-10, f_tol: 1e-20, min_derivative: 1e-30, ..SolverOptions::default() };
+
+        let newton: Root = schroder(f, ff, 1.0, 2.0, &opts).unwrap();
+        let modified: Root = schroder(f, ff, 3.0, 2.0, &opts).unwrap();
+
+        assert!((modified.x - 1.0).abs() < 1e-8, "{:?}", modified);
+        assert!(modified.iterations < newton.iterations, "{} and {} iterations", modified.iterations, newton.iterations);
+    }
+}
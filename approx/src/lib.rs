@@ -0,0 +1,491 @@
+/*
+ * Written By: Alex Jackson (asj18a@acu.edu)
+ *
+ * Implementation of Approximation Methods:
+ *  - Bisection method
+ *  - Newton’s method
+ *  - Secant method
+ *  - Muller’s method, in the real numbers and in the complex plane
+ *  - Divided-difference tables
+ *  - Aberth–Ehrlich method for all the roots of a polynomial
+ *  - Automatic bracket search for the bracketed methods
+ *  - Finding every root of a function on an interval
+ *  - Newton's and Broyden's methods for systems of nonlinear equations
+ *  - Inverse quadratic iteration
+ *  - False position method (Bracketed secant method)
+ *  - Brent's method
+ *  - Illinois, Pegasus and Anderson–Björck modified false position methods
+ *  - Ridders' method
+ *  - Halley's, Householder's and Schröder's methods
+ *  - Automatic differentiation with dual numbers for Newton's and Halley's methods
+ *  - Fixed-point iteration, Aitken's Δ² acceleration and Steffensen's method
+ *  - Iteration histories, exported as CSV or JSON
+ *  - Empirical order of convergence estimates
+ *  - Counting and memoizing function evaluations
+ *  - Generic floating-point types: f32, f64 and double-double precision
+ *
+ *  */
+
+//! Root-finding methods for functions of a single real variable.
+//!
+//! Every method takes a [`SolverOptions`] controlling its tolerances and
+//! limits, and returns a [`Root`] holding the root and the number of
+//! iterations once the approximation is within an acceptable level of error,
+//! or a [`RootError`] describing why it could not be found. Set
+//! [`SolverOptions::trace`] to also record the history of the method.
+
+mod brent;
+mod complex;
+mod divdiff;
+mod dual;
+mod error;
+mod fixed;
+mod householder;
+mod memo;
+mod options;
+mod order;
+mod poly;
+mod regula;
+mod root;
+mod search;
+mod system;
+mod trace;
+
+use std::cell::{Cell, RefCell};
+use std::ops::Sub;
+
+pub use crate::brent::brent;
+pub use crate::complex::muller_complex;
+pub use crate::divdiff::DividedDifferences;
+pub use crate::dual::{derivative, halley_ad, newton_ad, Dual, Scalar};
+pub use crate::error::RootError;
+pub use crate::fixed::{aitken, fixed_point, fixed_point_aitken, steffensen};
+pub use crate::householder::{halley, householder, schroder};
+pub use crate::memo::Memoized;
+pub use crate::options::SolverOptions;
+pub use crate::order::{convergence_order, Convergence};
+pub use crate::poly::{Polynomial, PolynomialRoot};
+pub use crate::regula::{anderson_bjorck, illinois, pegasus, ridders};
+pub use crate::root::Root;
+pub use crate::search::{all_roots, bracket_and_solve, expand_bracket, scan_brackets, BracketedMethod, IntervalRoot, RootKind};
+pub use crate::system::{broyden, finite_difference_jacobian, newton_system, Jacobian, System};
+pub use crate::trace::{Step, Trace, TraceValue};
+pub use num_complex::Complex;
+pub use real::{DoubleDouble, Real};
+pub use rulinalg::matrix::Matrix;
+pub use rulinalg::vector::Vector;
+
+// The options of a running method, along with the number of evaluations of the function and
+// of its derivatives so far and the history of its approximations of type T
+struct Solver<'a, T = f64> {
+    opts: &'a SolverOptions,
+    f_evaluations: Cell<usize>,
+    df_evaluations: Cell<usize>,
+    trace: RefCell<Trace<T>>,
+}
+
+impl<'a, T: Clone + Sub<Output = T>> Solver<'a, T> {
+    fn new(opts: &'a SolverOptions) -> Self {
+        Solver { opts, f_evaluations: Cell::new(0), df_evaluations: Cell::new(0), trace: RefCell::new(Trace::new()) }
+    }
+
+    // Evaluate f at x, counting the evaluation
+    fn eval<X, U>(&self, mut f: impl FnMut(X) -> U, x: X) -> U {
+        self.f_evaluations.set(self.f_evaluations.get() + 1);
+        f(x)
+    }
+
+    // Evaluate a derivative of f at x, counting the evaluation
+    fn eval_derivative<X, U>(&self, mut ff: impl FnMut(X) -> U, x: X) -> U {
+        self.df_evaluations.set(self.df_evaluations.get() + 1);
+        ff(x)
+    }
+
+    // The number of evaluations of f and its derivatives so far
+    fn evaluations(&self) -> usize {
+        self.f_evaluations.get() + self.df_evaluations.get()
+    }
+
+    // Determine if the approximation is below an acceptable level of error
+    fn done<R: Real>(&self, xn: R, xl: R, fx: R) -> bool {
+        self.converged((xn - xl).abs(), xn.abs(), fx.abs())
+    }
+
+    // Determine if a step of size dx to a point of size x, where |f| = fx, is below an acceptable level of error
+    fn converged<R: Real>(&self, dx: R, x: R, fx: R) -> bool {
+        dx <= R::from_f64(self.opts.x_abs_tol) + R::from_f64(self.opts.x_rel_tol) * x && fx <= R::from_f64(self.opts.f_tol)
+    }
+
+    // Ensure f(a) and f(b) do not have the same sign, returning the endpoint if either is already a root
+    fn bracket<R: Real>(&self, a: R, fa: R, b: R, fb: R) -> Result<Option<R>, RootError<R>> {
+        let zero: R = R::from_f64(0.0);
+
+        if fa * fb > zero || fa.is_nan() || fb.is_nan() {
+            Err(RootError::NoSignChange { a, fa, b, fb })
+        } else if fa == zero {
+            Ok(Some(a))
+        } else if fb == zero {
+            Ok(Some(b))
+        } else {
+            Ok(None)
+        }
+    }
+
+    // Determine if the method has run out of iterations or function evaluations
+    fn limit(&self, i: usize, last_x: T) -> Option<RootError<T>> {
+        if i >= self.opts.max_iterations {
+            Some(RootError::MaxIterations { last_x })
+        } else if self.evaluations() >= self.opts.max_evaluations {
+            Some(RootError::MaxEvaluations { last_x })
+        } else {
+            None
+        }
+    }
+
+    // Record the approximation x found in this iteration, if the history is being kept
+    fn record(&self, x: &T, fx: &T, bracket: Option<(T, T)>) {
+        if self.opts.trace {
+            self.trace.borrow_mut().push(x.clone(), fx.clone(), bracket, self.evaluations());
+        }
+    }
+
+    // The result of a method that converged to x after i iterations
+    fn finish(&self, x: T, iterations: usize) -> Root<T> {
+        let trace: Option<Trace<T>> = if self.opts.trace { Some(self.trace.replace(Trace::new())) } else { None };
+
+        Root { x, iterations, f_evaluations: self.f_evaluations.get(), df_evaluations: self.df_evaluations.get(), trace }
+    }
+}
+
+/// Bisection method on the bracket `x = (a, b)`.
+///
+/// `f(a)` and `f(b)` must have opposite signs. Like the other methods, `f`
+/// may be a closure that keeps state between calls:
+///
+/// ```
+/// use approx::{bisection, SolverOptions};
+///
+/// let mut points: Vec<f64> = Vec::new();
+/// let root = bisection(|x: f64| { points.push(x); x * x - 2.0 }, (1.0, 2.0), &SolverOptions::default()).unwrap();
+///
+/// assert_eq!(points.len(), root.f_evaluations);
+/// ```
+pub fn bisection<R: Real>(mut f: impl FnMut(R) -> R, x: (R, R), opts: &SolverOptions) -> Result<Root<R>, RootError<R>> {
+    let s = Solver::new(opts);
+    let zero: R = R::from_f64(0.0);
+    let (mut a, mut b) = x;
+
+    // Calculate y values
+    let mut fa: R = s.eval(&mut f, a);
+    let fb: R = s.eval(&mut f, b);
+
+    // Ensure signs are not the same
+    if let Some(root) = s.bracket(a, fa, b, fb)? {
+        return Ok(s.finish(root, 0));
+    }
+
+    let mut i: usize = 0;
+
+    loop {
+        // Find the midpoint
+        let c: R = (b - a) / R::from_f64(2.0) + a;
+        let fc: R = s.eval(&mut f, c);
+
+        s.record(&c, &fc, Some((a, b)));
+
+        if !fc.is_finite() {
+            return Err(RootError::NonFinite);
+        }
+
+        if s.done(a, c, fc) {
+            return Ok(s.finish(c, i));
+        }
+
+        if let Some(e) = s.limit(i, c) {
+            return Err(e);
+        }
+
+        // Determine new bounds
+        if (fa > zero) == (fc > zero) {
+            a = c;
+            fa = fc;
+        } else {
+            b = c;
+        }
+
+        i += 1;
+    }
+}
+
+/// Newton's method for `f` with derivative `ff`, starting from `x`.
+///
+/// Fails with [`RootError::ZeroDerivative`] once `|ff(x)|` drops below
+/// `opts.min_derivative`.
+pub fn newton<R: Real>(mut f: impl FnMut(R) -> R, mut ff: impl FnMut(R) -> R, x: R, opts: &SolverOptions) -> Result<Root<R>, RootError<R>> {
+    let s = Solver::new(opts);
+
+    iterate(&s, x, |x| {
+        let fx: R = s.eval(&mut f, x);
+        let dfx: R = s.eval_derivative(&mut ff, x);
+
+        (fx, dfx, x - fx / dfx)
+    })
+}
+
+// Repeatedly apply a derivative-based step, which returns f(x), f'(x) and the next approximation,
+// until the approximation converges
+fn iterate<R: Real>(s: &Solver<R>, mut x: R, mut step: impl FnMut(R) -> (R, R, R)) -> Result<Root<R>, RootError<R>> {
+    let mut i: usize = 0;
+
+    loop {
+        let (fx, dfx, xn) = step(x);
+
+        s.record(&x, &fx, None);
+
+        // x is exactly a root, where the step may be 0 / 0 and the derivative may vanish,
+        // as it does at a multiple root
+        if fx == R::from_f64(0.0) {
+            return Ok(s.finish(x, i));
+        }
+
+        if s.done(xn, x, fx) {
+            return Ok(s.finish(xn, i));
+        }
+
+        if let Some(e) = s.limit(i, x) {
+            return Err(e);
+        }
+
+        if dfx.abs() <= R::from_f64(s.opts.min_derivative) {
+            return Err(RootError::ZeroDerivative { x });
+        }
+
+        if !xn.is_finite() {
+            return Err(RootError::NonFinite);
+        }
+
+        x = xn;
+        i += 1;
+    }
+}
+
+/// Secant method starting from the two points `x = (x0, x1)`.
+///
+/// Fails with [`RootError::ZeroDerivative`] once the slope of the secant line
+/// drops below `opts.min_derivative`.
+pub fn secant<R: Real>(mut f: impl FnMut(R) -> R, x: (R, R), opts: &SolverOptions) -> Result<Root<R>, RootError<R>> {
+    let s = Solver::new(opts);
+    let (mut x0, mut x1) = x;
+    let mut fx0: R = s.eval(&mut f, x0);
+    let mut fx1: R = s.eval(&mut f, x1);
+    let mut i: usize = 0;
+
+    loop {
+        s.record(&x1, &fx1, None);
+
+        // x1 is exactly a root, where the next step would be 0 / 0
+        if fx1 == R::from_f64(0.0) {
+            return Ok(s.finish(x1, i));
+        }
+
+        if x1 == x0 {
+            return Err(RootError::ZeroDerivative { x: x1 });
+        }
+
+        let x2: R = x1 - fx1 * ((x1 - x0) / (fx1 - fx0));
+
+        if s.done(x1, x0, fx1) {
+            return Ok(s.finish(if x2.is_finite() { x2 } else { x1 }, i));
+        }
+
+        if let Some(e) = s.limit(i, x1) {
+            return Err(e);
+        }
+
+        if ((fx1 - fx0) / (x1 - x0)).abs() <= R::from_f64(opts.min_derivative) {
+            return Err(RootError::ZeroDerivative { x: x1 });
+        }
+
+        if !x2.is_finite() {
+            return Err(RootError::NonFinite);
+        }
+
+        (x0, fx0) = (x1, fx1);
+        (x1, fx1) = (x2, s.eval(&mut f, x2));
+        i += 1;
+    }
+}
+
+/// Muller's method starting from the three points `x = (x0, x1, x2)`.
+///
+/// Fails with [`RootError::ComplexStep`] if the next approximation would be a
+/// complex number; use [`muller_complex`] to continue into the complex plane.
+pub fn muller<R: Real>(mut g: impl FnMut(R) -> R, mut x: (R, R, R), opts: &SolverOptions) -> Result<Root<R>, RootError<R>> {
+    let s = Solver::new(opts);
+    let mut f = |x: R| s.eval(&mut g, x);
+    let mut fx: (R, R, R) = (f(x.0), f(x.1), f(x.2));
+    let mut i: usize = 0;
+
+    loop {
+        s.record(&x.2, &fx.2, None);
+
+        // x2 is exactly a root, where the next interpolant would be degenerate
+        if fx.2 == R::from_f64(0.0) || s.done(x.2, x.1, fx.2) {
+            return Ok(s.finish(x.2, i));
+        }
+
+        if let Some(e) = s.limit(i, x.2) {
+            return Err(e);
+        }
+
+        // f[x2, x1] + f[x2, x0] - f[x1, x0] = f[x2, x1] + (x2 - x1) f[x2, x1, x0]
+        let table = DividedDifferences::from_values(&[x.2, x.1, x.0], &[fx.2, fx.1, fx.0]);
+        let w: R = table.get(0, 1) + (x.2 - x.1) * table.get(0, 2);
+        let d: R = (w.powi(2) - R::from_f64(4.0) * fx.2 * table.get(0, 2)).sqrt();
+
+        if d.is_nan() {
+            return Err(RootError::ComplexStep);
+        }
+
+        let x3: R = x.2 + ((R::from_f64(-2.0) * fx.2) / (if (w - d).abs() > (w + d).abs() { w - d } else { w + d }));
+
+        if !x3.is_finite() {
+            return Err(RootError::NonFinite);
+        }
+
+        x = (x.1, x.2, x3);
+        fx = (fx.1, fx.2, f(x3));
+        i += 1;
+    }
+}
+
+/// Inverse quadratic iteration starting from the three points `x = (x0, x1, x2)`.
+pub fn invquad<R: Real>(mut f: impl FnMut(R) -> R, mut x: (R, R, R), opts: &SolverOptions) -> Result<Root<R>, RootError<R>> {
+    let s = Solver::new(opts);
+    let mut fx: (R, R, R) = (s.eval(&mut f, x.0), s.eval(&mut f, x.1), s.eval(&mut f, x.2));
+    let mut i: usize = 0;
+
+    loop {
+        s.record(&x.2, &fx.2, None);
+
+        // x2 is exactly a root, where the next interpolant would divide by zero
+        if fx.2 == R::from_f64(0.0) {
+            return Ok(s.finish(x.2, i));
+        }
+
+        if let Some(e) = s.limit(i, x.2) {
+            return Err(e);
+        }
+
+        if s.done(x.2, x.1, fx.1) {
+            return Ok(s.finish(x.2, i));
+        }
+
+        let a: R = (x.2 - x.1) / (fx.2 - fx.1);
+        let b: R = (R::from_f64(1.0) / (fx.2 - fx.0)) * (((x.2 - x.1) / (fx.2 - fx.1)) - ((x.1 - x.0) / (fx.1 - fx.0)));
+        let x3: R = x.2 - a * fx.2 + b * fx.2 * fx.1;
+
+        if !x3.is_finite() {
+            return Err(RootError::NonFinite);
+        }
+
+        x = (x.1, x.2, x3);
+        fx = (fx.1, fx.2, s.eval(&mut f, x3));
+        i += 1;
+    }
+}
+
+/// False position (bracketed secant) method on the bracket `x = (a, b)`.
+///
+/// `f(a)` and `f(b)` must have opposite signs.
+pub fn falsepos<R: Real>(mut f: impl FnMut(R) -> R, x: (R, R), opts: &SolverOptions) -> Result<Root<R>, RootError<R>> {
+    let s = Solver::new(opts);
+    let zero: R = R::from_f64(0.0);
+    let (mut a, mut b) = x;
+
+    // Calculate y values
+    let mut fa: R = s.eval(&mut f, a);
+    let mut fb: R = s.eval(&mut f, b);
+
+    // Ensure signs are not the same
+    if let Some(root) = s.bracket(a, fa, b, fb)? {
+        return Ok(s.finish(root, 0));
+    }
+
+    let mut i: usize = 0;
+
+    loop {
+        // Find the secant's intersection with the x axis
+        let c: R = (a * fb - b * fa) / (fb - fa);
+        let fc: R = s.eval(&mut f, c);
+
+        s.record(&c, &fc, Some((a, b)));
+
+        if s.done(a, c, fc) {
+            return Ok(s.finish(c, i));
+        }
+
+        if !fc.is_finite() {
+            return Err(RootError::NonFinite);
+        }
+
+        if let Some(e) = s.limit(i, c) {
+            return Err(e);
+        }
+
+        // Determine new bounds
+        if (fa > zero) == (fc > zero) {
+            a = c;
+            fa = fc;
+        } else {
+            b = c;
+            fb = fc;
+        }
+
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn secant_exact_root() {
+        let root: Root = secant(|x: f64| x - 1.0, (0.0, 1.0), &SolverOptions::default()).unwrap();
+
+        assert_eq!(root.x, 1.0);
+    }
+
+    #[test]
+    fn secant_equal_points() {
+        let result = secant(|x: f64| x * x - 2.0, (1.0, 1.0), &SolverOptions::default());
+
+        assert!(matches!(result, Err(RootError::ZeroDerivative { .. })), "{:?}", result);
+    }
+
+    #[test]
+    fn muller_exact_root() {
+        let root: Root = muller(|x: f64| x - 1.0, (0.0, 0.5, 2.0), &SolverOptions::default()).unwrap();
+
+        assert_eq!(root.x, 1.0);
+    }
+
+    #[test]
+    fn invquad_exact_root() {
+        let root: Root = invquad(|x: f64| x - 1.0, (0.0, 1.0, 2.0), &SolverOptions::default()).unwrap();
+
+        assert_eq!(root.x, 1.0);
+    }
+
+    #[test]
+    fn open_methods_converge() {
+        let f = |x: f64| x.powi(3) - 4.0 * x.powi(2) + 3.0 * x + 1.0;
+        let opts: SolverOptions = SolverOptions::default();
+        let expected: f64 = -0.24697960371746706;
+
+        assert!((secant(f, (-1.0, 0.0), &opts).unwrap().x - expected).abs() < 1e-8);
+        assert!((muller(f, (-1.0, -0.5, 0.0), &opts).unwrap().x - expected).abs() < 1e-8);
+        assert!((invquad(f, (-1.0, -0.5, 0.0), &opts).unwrap().x - expected).abs() < 1e-8);
+    }
+}
@@ -0,0 +1,65 @@
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+/// Wraps a function of a real variable, counting how many times it is
+/// called and remembering every value it computes, so it is only ever
+/// evaluated once at each point.
+///
+/// Useful when `f` is expensive, such as a simulation: pass
+/// `|x| f.call(x)` to any method, and reuse the same wrapper across methods
+/// to share the values already computed.
+///
+/// Only `f64` functions can be memoized: values are keyed on the exact bit
+/// pattern of `x`, and [`Real`](crate::Real) gives no way to hash a value.
+/// `f` is called through `&self` so the wrapper can be shared between
+/// closures, which is why it must be `Fn` rather than `FnMut`.
+///
+/// ```
+/// use approx::{brent, Memoized, SolverOptions};
+///
+/// let f = Memoized::new(|x: f64| x.powf(3.0) - 4.0 * x.powf(2.0) + 3.0 * x + 1.0);
+/// let opts = SolverOptions::default();
+///
+/// let first = brent(|x| f.call(x), (-1.0, 0.0), &opts).unwrap();
+/// let again = brent(|x| f.call(x), (-1.0, 0.0), &opts).unwrap();
+///
+/// // The second run only used remembered values
+/// assert_eq!(first.x, again.x);
+/// assert_eq!(f.evaluations(), first.f_evaluations);
+/// ```
+pub struct Memoized<F> {
+    f: F,
+    values: RefCell<HashMap<u64, f64>>,
+    calls: Cell<usize>,
+}
+
+impl<F: Fn(f64) -> f64> Memoized<F> {
+    pub fn new(f: F) -> Self {
+        Memoized { f, values: RefCell::new(HashMap::new()), calls: Cell::new(0) }
+    }
+
+    /// The value of the function at `x`, only evaluating it if it has not
+    /// been evaluated at exactly `x` before.
+    pub fn call(&self, x: f64) -> f64 {
+        self.calls.set(self.calls.get() + 1);
+
+        if let Some(y) = self.values.borrow().get(&x.to_bits()) {
+            return *y;
+        }
+
+        let y: f64 = (self.f)(x);
+
+        self.values.borrow_mut().insert(x.to_bits(), y);
+        y
+    }
+
+    /// The number of distinct points the function has been evaluated at.
+    pub fn evaluations(&self) -> usize {
+        self.values.borrow().len()
+    }
+
+    /// The number of times [`call`](Memoized::call) has been called, including those answered from memory.
+    pub fn calls(&self) -> usize {
+        self.calls.get()
+    }
+}
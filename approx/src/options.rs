@@ -0,0 +1,42 @@
+/// Tolerances and limits shared by every root-finding method.
+///
+/// A method has converged once both the change in `x` is within
+/// `x_abs_tol + x_rel_tol * |x|` and `|f(x)|` is within `f_tol`. Override
+/// individual fields with struct update syntax:
+///
+/// ```
+/// use approx::SolverOptions;
+///
+/// let opts = SolverOptions { f_tol: 1e-14, ..SolverOptions::default() };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolverOptions {
+    /// Absolute tolerance on the change in `x`
+    pub x_abs_tol: f64,
+    /// Tolerance on the change in `x` relative to `|x|`
+    pub x_rel_tol: f64,
+    /// Tolerance on `|f(x)|`
+    pub f_tol: f64,
+    /// Derivatives (or secant slopes) smaller than this in magnitude are treated as zero
+    pub min_derivative: f64,
+    /// Maximum number of iterations before giving up
+    pub max_iterations: usize,
+    /// Maximum number of evaluations of `f` (and its derivatives) before giving up
+    pub max_evaluations: usize,
+    /// Record the history of the method in [`Root::trace`](crate::Root::trace)
+    pub trace: bool,
+}
+
+impl Default for SolverOptions {
+    fn default() -> Self {
+        SolverOptions {
+            x_abs_tol: 1e-8,
+            x_rel_tol: 0.0,
+            f_tol: 1e-8,
+            min_derivative: 1e-6,
+            max_iterations: 1000,
+            max_evaluations: usize::MAX,
+            trace: false,
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use crate::Trace;
+
+/// An estimate of how fast a sequence converges, `e[k + 1] ≈ rate * e[k]^order`,
+/// where `e[k]` is the error of the `k`th approximation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Convergence {
+    /// The order of convergence: 1 is linear, 2 is quadratic
+    pub order: f64,
+    /// The asymptotic error constant
+    pub rate: f64,
+}
+
+// Errors this much larger than the rounding error in the root are still used by the estimate
+const NOISE: f64 = 1e3 * f64::EPSILON;
+
+/// Estimates the order and rate of convergence of the approximations `x`.
+///
+/// The errors are `|x[k] - root|` when the root is known, and otherwise the
+/// distances `|x[k + 1] - x[k]|` between approximations, which shrink at the
+/// same rate. The estimate uses the last three errors that are decreasing
+/// and still well above rounding error, since the order only holds
+/// asymptotically. Returns `None` if there are no such three errors.
+///
+/// ```
+/// use approx::convergence_order;
+///
+/// // Each error is the square of the last
+/// let x: Vec<f64> = vec![1.1, 1.01, 1.0001, 1.00000001];
+/// let estimate = convergence_order(&x, Some(1.0)).unwrap();
+///
+/// assert!((estimate.order - 2.0).abs() < 1e-3);
+/// ```
+pub fn convergence_order(x: &[f64], root: Option<f64>) -> Option<Convergence> {
+    let (errors, scale): (Vec<f64>, f64) = match root {
+        Some(r) => (x.iter().map(|x| (x - r).abs()).collect(), r.abs().max(1.0)),
+        None => (x.windows(2).map(|w| (w[1] - w[0]).abs()).collect(), x.last()?.abs().max(1.0)),
+    };
+
+    errors.windows(3).rev().find_map(|e| {
+        if !(e[2] > NOISE * scale && e[2] < e[1] && e[1] < e[0] && e[0].is_finite()) {
+            return None;
+        }
+
+        let order: f64 = (e[2] / e[1]).ln() / (e[1] / e[0]).ln();
+
+        Some(Convergence { order, rate: e[2] / e[1].powf(order) })
+    })
+}
+
+impl Trace<f64> {
+    /// Estimates the order and rate of convergence of the approximations in
+    /// the trace, using [`convergence_order`].
+    pub fn convergence(&self, root: Option<f64>) -> Option<Convergence> {
+        convergence_order(&self.iterates(), root)
+    }
+}
@@ -0,0 +1,312 @@
+use std::f64::consts::PI;
+
+use num_complex::Complex;
+
+use crate::{RootError, Solver, SolverOptions};
+
+/// A polynomial with real coefficients.
+///
+/// The coefficients are stored from the constant term up, so
+/// `Polynomial::new(vec![1.0, 3.0, -4.0, 1.0])` is `x^3 - 4x^2 + 3x + 1`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polynomial {
+    coefficients: Vec<f64>,
+}
+
+/// A root of a [`Polynomial`], along with its estimated multiplicity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PolynomialRoot {
+    pub root: Complex<f64>,
+    pub multiplicity: usize,
+}
+
+impl Polynomial {
+    /// Creates the polynomial `c[0] + c[1] x + ... + c[n] x^n`, dropping any
+    /// leading zero coefficients.
+    pub fn new(mut coefficients: Vec<f64>) -> Self {
+        while coefficients.last() == Some(&0.0) {
+            coefficients.pop();
+        }
+
+        Polynomial { coefficients }
+    }
+
+    /// The coefficients, from the constant term up.
+    pub fn coefficients(&self) -> &[f64] {
+        &self.coefficients
+    }
+
+    /// The degree of the polynomial, taking the zero polynomial to have degree 0.
+    pub fn degree(&self) -> usize {
+        self.coefficients.len().saturating_sub(1)
+    }
+
+    /// Evaluates the polynomial at `x` using Horner's method.
+    pub fn eval(&self, x: f64) -> f64 {
+        self.coefficients.iter().rev().fold(0.0, |y, c| y * x + c)
+    }
+
+    /// Evaluates the polynomial at the complex number `z` using Horner's method.
+    pub fn eval_complex(&self, z: Complex<f64>) -> Complex<f64> {
+        self.coefficients.iter().rev().fold(Complex::new(0.0, 0.0), |y, c| y * z + c)
+    }
+
+    /// The derivative of the polynomial.
+    pub fn derivative(&self) -> Polynomial {
+        Polynomial::new(self.coefficients.iter().enumerate().skip(1).map(|(i, c)| i as f64 * c).collect())
+    }
+
+    /// Divides the polynomial by `x - r` using synthetic division, returning
+    /// the quotient and the remainder, which is the value of the polynomial at `r`.
+    pub fn deflate(&self, r: f64) -> (Polynomial, f64) {
+        let mut quotient: Vec<f64> = vec![0.0; self.degree()];
+        let mut remainder: f64 = 0.0;
+
+        for (i, c) in self.coefficients.iter().enumerate().rev() {
+            if i > 0 {
+                quotient[i - 1] = remainder * r + c;
+                remainder = quotient[i - 1];
+            } else {
+                remainder = remainder * r + c;
+            }
+        }
+
+        (Polynomial::new(quotient), remainder)
+    }
+
+    /// Finds every real and complex root of the polynomial using the Aberth–Ehrlich method.
+    ///
+    /// All `n` roots are approximated at once: each approximation takes a
+    /// Newton step on the polynomial implicitly deflated by every other
+    /// approximation, which keeps them from converging to the same root.
+    /// Approximations that cluster around the same point are then merged into
+    /// a single root whose multiplicity is the size of the cluster, and each
+    /// root of multiplicity `m` is polished with Newton's method on the
+    /// `(m - 1)`th derivative, where it is a simple root.
+    /// Roots at zero are removed exactly with [`deflate`](Polynomial::deflate)
+    /// before iterating. Roots are
+    /// returned in order of their real part and then their imaginary part.
+    pub fn roots(&self, opts: &SolverOptions) -> Result<Vec<PolynomialRoot>, RootError<Complex<f64>>> {
+        let mut roots: Vec<PolynomialRoot> = Vec::new();
+
+        // Deflate out roots at zero, where synthetic division by x is exact
+        let mut p: Polynomial = self.clone();
+        let mut zeros: usize = 0;
+
+        while p.degree() > 0 && p.coefficients[0] == 0.0 {
+            p = p.deflate(0.0).0;
+            zeros += 1;
+        }
+
+        if zeros > 0 {
+            roots.push(PolynomialRoot { root: Complex::new(0.0, 0.0), multiplicity: zeros });
+        }
+
+        if p.degree() == 0 {
+            return Ok(roots);
+        }
+
+        let z: Vec<Complex<f64>> = aberth(&p, opts)?;
+        let dp: Polynomial = p.derivative();
+
+        // Estimate the distance of each approximation from its root by its Newton step,
+        // which for a root of multiplicity m is about 1/m of the true distance
+        let radius: Vec<f64> = z.iter().map(|z| {
+            let step: f64 = (p.eval_complex(*z) / dp.eval_complex(*z)).norm();
+
+            16.0 * if step.is_finite() { step } else { 0.0 } + opts.x_abs_tol + opts.x_rel_tol * z.norm()
+        }).collect();
+
+        // Group approximations that are within each other's radius
+        let mut cluster: Vec<usize> = (0..z.len()).collect();
+
+        for j in 0..z.len() {
+            for k in 0..j {
+                if (z[j] - z[k]).norm() <= radius[j].max(radius[k]) {
+                    let (from, to) = (cluster[j], cluster[k]);
+
+                    for c in cluster.iter_mut().filter(|c| **c == from) {
+                        *c = to;
+                    }
+                }
+            }
+        }
+
+        for c in 0..z.len() {
+            let members: Vec<usize> = (0..z.len()).filter(|k| cluster[*k] == c).collect();
+
+            if members.is_empty() {
+                continue;
+            }
+
+            let m: usize = members.len();
+            let centroid: Complex<f64> = members.iter().map(|k| z[*k]).sum::<Complex<f64>>() / m as f64;
+            let mut root: Complex<f64> = polish(&p, centroid, m, opts);
+
+            // Real coefficients give real roots or conjugate pairs, so snap roots that are nearly real
+            if root.im.abs() <= members.iter().map(|k| radius[*k]).fold(0.0, f64::max) {
+                root.im = 0.0;
+            }
+
+            roots.push(PolynomialRoot { root, multiplicity: m });
+        }
+
+        roots.sort_by(|a, b| a.root.re.total_cmp(&b.root.re).then(a.root.im.total_cmp(&b.root.im)));
+
+        Ok(roots)
+    }
+}
+
+// Approximate all the roots of p at once with the Aberth–Ehrlich method
+fn aberth(p: &Polynomial, opts: &SolverOptions) -> Result<Vec<Complex<f64>>, RootError<Complex<f64>>> {
+    let s = Solver::new(opts);
+    let dp: Polynomial = p.derivative();
+    let n: usize = p.degree();
+    let lead: f64 = p.coefficients[n];
+    let magnitude: Polynomial = Polynomial::new(p.coefficients.iter().map(|c| c.abs()).collect());
+
+    // Start on a circle that contains every root (Cauchy's bound), rotated off the real axis
+    let bound: f64 = 1.0 + p.coefficients[..n].iter().map(|c| (c / lead).abs()).fold(0.0, f64::max);
+    let mut z: Vec<Complex<f64>> = (0..n).map(|k| Complex::from_polar(bound, 2.0 * PI * k as f64 / n as f64 + 0.4)).collect();
+    let mut converged: Vec<bool> = vec![false; n];
+    let mut i: usize = 0;
+
+    while converged.contains(&false) {
+        if let Some(e) = s.limit(i, z[converged.iter().position(|c| !c).unwrap_or(0)]) {
+            return Err(e);
+        }
+
+        for k in 0..n {
+            if converged[k] {
+                continue;
+            }
+
+            let pz: Complex<f64> = s.eval(|z| p.eval_complex(z), z[k]);
+
+            // Stop once p(z) is as small as the rounding error in evaluating it
+            if pz.norm() <= 4.0 * f64::EPSILON * magnitude.eval(z[k].norm()) {
+                converged[k] = true;
+                continue;
+            }
+
+            let ratio: Complex<f64> = pz / s.eval_derivative(|z| dp.eval_complex(z), z[k]);
+            let repulsion: Complex<f64> = (0..n).filter(|j| *j != k).map(|j| 1.0 / (z[k] - z[j])).sum();
+            let w: Complex<f64> = ratio / (1.0 - ratio * repulsion);
+
+            if !w.is_finite() {
+                return Err(RootError::NonFinite);
+            }
+
+            z[k] -= w;
+            converged[k] = s.converged(w.norm(), z[k].norm(), 0.0);
+        }
+
+        i += 1;
+    }
+
+    Ok(z)
+}
+
+// Refine an approximation of a root of multiplicity m with Newton's method on the
+// (m - 1)th derivative of p, which has a simple root there, keeping the best approximation
+fn polish(p: &Polynomial, z: Complex<f64>, m: usize, opts: &SolverOptions) -> Complex<f64> {
+    let mut q: Polynomial = p.clone();
+
+    for _ in 1..m {
+        q = q.derivative();
+    }
+
+    let dq: Polynomial = q.derivative();
+    let (mut best, mut best_q) = (z, q.eval_complex(z).norm());
+    let mut z: Complex<f64> = z;
+
+    for _ in 0..opts.max_iterations.min(10) {
+        let step: Complex<f64> = q.eval_complex(z) / dq.eval_complex(z);
+
+        if !step.is_finite() {
+            break;
+        }
+
+        z -= step;
+
+        let qz: f64 = q.eval_complex(z).norm();
+
+        if qz < best_q {
+            (best, best_q) = (z, qz);
+        } else {
+            break;
+        }
+    }
+
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Whether the roots found are the expected roots, with their multiplicities, to within tolerance.
+    // Roots with the same real part are compared in any order, since rounding decides their order
+    fn assert_roots(coefficients: Vec<f64>, expected: &[(f64, f64, usize)], tolerance: f64) {
+        let roots: Vec<PolynomialRoot> = Polynomial::new(coefficients).roots(&SolverOptions::default()).unwrap();
+
+        assert_eq!(roots.len(), expected.len(), "{:?}", roots);
+
+        for &(re, im, multiplicity) in expected {
+            let found: bool = roots.iter().any(|r| (r.root - Complex::new(re, im)).norm() <= tolerance && r.multiplicity == multiplicity);
+
+            assert!(found, "{} + {}i with multiplicity {} is not in {:?}", re, im, multiplicity, roots);
+        }
+    }
+
+    #[test]
+    fn simple_roots() {
+        // x^3 - 4x^2 + 3x + 1
+        assert_roots(vec![1.0, 3.0, -4.0, 1.0], &[
+            (-0.24697960371746706, 0.0, 1),
+            (1.445041867912629, 0.0, 1),
+            (2.801937735804838, 0.0, 1),
+        ], 1e-12);
+    }
+
+    #[test]
+    fn repeated_roots() {
+        // (x - 1)^3 (x + 2)
+        assert_roots(vec![-2.0, 5.0, -3.0, -1.0, 1.0], &[(-2.0, 0.0, 1), (1.0, 0.0, 3)], 1e-8);
+
+        // (x - 3)^2 (x^2 + 1)^2
+        assert_roots(vec![9.0, -6.0, 19.0, -12.0, 11.0, -6.0, 1.0], &[(0.0, -1.0, 2), (0.0, 1.0, 2), (3.0, 0.0, 2)], 1e-8);
+    }
+
+    #[test]
+    fn complex_pairs() {
+        // x^2 + 1
+        assert_roots(vec![1.0, 0.0, 1.0], &[(0.0, -1.0, 1), (0.0, 1.0, 1)], 1e-12);
+
+        // (x^2 + 1)(x^2 - 2x + 5)
+        assert_roots(vec![5.0, -2.0, 6.0, -2.0, 1.0], &[(0.0, -1.0, 1), (0.0, 1.0, 1), (1.0, -2.0, 1), (1.0, 2.0, 1)], 1e-12);
+    }
+
+    #[test]
+    fn zero_roots() {
+        // x^3 (x - 2)
+        assert_roots(vec![0.0, 0.0, 0.0, -2.0, 1.0], &[(0.0, 0.0, 3), (2.0, 0.0, 1)], 1e-12);
+
+        // x^2 (x^2 + 4)
+        assert_roots(vec![0.0, 0.0, 4.0, 0.0, 1.0], &[(0.0, -2.0, 1), (0.0, 0.0, 2), (0.0, 2.0, 1)], 1e-12);
+
+        // x^2, and constants, which have no roots
+        assert_roots(vec![0.0, 0.0, 1.0], &[(0.0, 0.0, 2)], 0.0);
+        assert_roots(vec![3.0], &[], 0.0);
+        assert_roots(vec![0.0, 0.0], &[], 0.0);
+    }
+
+    #[test]
+    fn deflate() {
+        // x^3 - 4x^2 + 3x + 1 = (x - 2)(x^2 - 2x - 1) - 1
+        let (quotient, remainder) = Polynomial::new(vec![1.0, 3.0, -4.0, 1.0]).deflate(2.0);
+
+        assert_eq!(quotient, Polynomial::new(vec![-1.0, -2.0, 1.0]));
+        assert_eq!(remainder, -1.0);
+    }
+}
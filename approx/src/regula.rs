@@ -0,0 +1,185 @@
+use crate::{Real, Root, RootError, Solver, SolverOptions};
+
+/// Illinois modified false position method on the bracket `x = (a, b)`.
+///
+/// Halves the function value at an endpoint each time it is retained, which
+/// keeps it from stalling the way plain [`falsepos`](crate::falsepos) does.
+pub fn illinois<R: Real>(f: impl FnMut(R) -> R, x: (R, R), opts: &SolverOptions) -> Result<Root<R>, RootError<R>> {
+    modified_falsepos(f, x, opts, |_, _| R::from_f64(0.5))
+}
+
+/// Pegasus modified false position method on the bracket `x = (a, b)`.
+///
+/// Scales the function value at a retained endpoint by `f(b) / (f(b) + f(c))`.
+pub fn pegasus<R: Real>(f: impl FnMut(R) -> R, x: (R, R), opts: &SolverOptions) -> Result<Root<R>, RootError<R>> {
+    modified_falsepos(f, x, opts, |fb, fc| fb / (fb + fc))
+}
+
+/// Anderson–Björck modified false position method on the bracket `x = (a, b)`.
+///
+/// Scales the function value at a retained endpoint by `1 - f(c) / f(b)`,
+/// falling back to the Illinois factor of one half when that is not positive.
+pub fn anderson_bjorck<R: Real>(f: impl FnMut(R) -> R, x: (R, R), opts: &SolverOptions) -> Result<Root<R>, RootError<R>> {
+    modified_falsepos(f, x, opts, |fb, fc| {
+        let m: R = R::from_f64(1.0) - fc / fb;
+
+        if m > R::from_f64(0.0) { m } else { R::from_f64(0.5) }
+    })
+}
+
+// False position where, whenever the new point c lands on the same side as the last one,
+// f at the retained endpoint a is multiplied by scale(f(b), f(c)) to pull the next secant towards it
+fn modified_falsepos<R: Real>(mut f: impl FnMut(R) -> R, x: (R, R), opts: &SolverOptions, scale: fn(R, R) -> R) -> Result<Root<R>, RootError<R>> {
+    let s = Solver::new(opts);
+    let zero: R = R::from_f64(0.0);
+    let (mut a, mut b) = x;
+    let mut fa: R = s.eval(&mut f, a);
+    let mut fb: R = s.eval(&mut f, b);
+
+    // Ensure signs are not the same
+    if let Some(root) = s.bracket(a, fa, b, fb)? {
+        return Ok(s.finish(root, 0));
+    }
+
+    let mut i: usize = 0;
+
+    loop {
+        // Find the secant's intersection with the x axis
+        let c: R = (a * fb - b * fa) / (fb - fa);
+        let fc: R = s.eval(&mut f, c);
+
+        s.record(&c, &fc, Some((a, b)));
+
+        if !fc.is_finite() {
+            return Err(RootError::NonFinite);
+        }
+
+        if fc == zero || s.done(c, b, fc) {
+            return Ok(s.finish(c, i));
+        }
+
+        if let Some(e) = s.limit(i, c) {
+            return Err(e);
+        }
+
+        // b is always the most recent point, and the root stays between a and b
+        if (fc > zero) != (fb > zero) {
+            (a, fa) = (b, fb);
+        } else {
+            fa *= scale(fb, fc);
+        }
+
+        (b, fb) = (c, fc);
+        i += 1;
+    }
+}
+
+/// Ridders' method on the bracket `x = (a, b)`.
+///
+/// Evaluates the midpoint of the bracket, then applies false position to
+/// `f` multiplied by an exponential chosen to make the three points
+/// collinear. Converges quadratically while always keeping the root
+/// bracketed.
+pub fn ridders<R: Real>(mut f: impl FnMut(R) -> R, x: (R, R), opts: &SolverOptions) -> Result<Root<R>, RootError<R>> {
+    let s = Solver::new(opts);
+    let zero: R = R::from_f64(0.0);
+    let (mut a, mut b) = x;
+    let mut fa: R = s.eval(&mut f, a);
+    let mut fb: R = s.eval(&mut f, b);
+
+    // Ensure signs are not the same
+    if let Some(root) = s.bracket(a, fa, b, fb)? {
+        return Ok(s.finish(root, 0));
+    }
+
+    let mut last: R = R::from_f64(f64::NAN);
+    let mut i: usize = 0;
+
+    loop {
+        // Find the midpoint
+        let m: R = (a + b) / R::from_f64(2.0);
+        let fm: R = s.eval(&mut f, m);
+        let d: R = (fm * fm - fa * fb).sqrt();
+
+        if d == zero {
+            return Ok(s.finish(m, i));
+        }
+
+        let c: R = m + (m - a) * (fa - fb).signum() * fm / d;
+        let fc: R = s.eval(&mut f, c);
+
+        s.record(&c, &fc, Some((a, b)));
+
+        if !fc.is_finite() {
+            return Err(RootError::NonFinite);
+        }
+
+        if fc == zero || s.done(c, last, fc) {
+            return Ok(s.finish(c, i));
+        }
+
+        if let Some(e) = s.limit(i, c) {
+            return Err(e);
+        }
+
+        // Determine new bounds from whichever pair of points still brackets the root
+        if (fm > zero) != (fc > zero) {
+            (a, fa) = (m, fm);
+            (b, fb) = (c, fc);
+        } else if (fa > zero) != (fc > zero) {
+            (b, fb) = (c, fc);
+        } else {
+            (a, fa) = (c, fc);
+        }
+
+        if s.done(a, b, fc) {
+            return Ok(s.finish(c, i));
+        }
+
+        last = c;
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::falsepos;
+
+    use std::f64::consts::FRAC_PI_3;
+
+    // Plain false position keeps one endpoint of every bracket below, so it only converges linearly
+    fn g(x: f64) -> f64 {
+        x.exp() * (1.0 - 2.0 * x.cos())
+    }
+
+    type Method = fn(fn(f64) -> f64, (f64, f64), &SolverOptions) -> Result<Root, RootError>;
+
+    #[test]
+    fn faster_than_falsepos() {
+        let methods: [(&str, Method); 4] = [("Illinois", illinois), ("Pegasus", pegasus), ("Anderson-Björck", anderson_bjorck), ("Ridders", ridders)];
+
+        for (x, root) in [((-1.25, -0.75), -FRAC_PI_3), ((-2.0, 0.0), -FRAC_PI_3), ((0.0, 2.0), FRAC_PI_3)] {
+            for tol in [1e-8, 1e-12] {
+                let opts: SolverOptions = SolverOptions { x_abs_tol: tol, f_tol: tol, ..SolverOptions::default() };
+                let plain: Root = falsepos(g, x, &opts).unwrap();
+
+                for (name, method) in methods {
+                    let found: Root = method(g, x, &opts).unwrap();
+
+                    assert!((found.x - root).abs() < 1e-8, "{} found {} on {:?}", name, found.x, x);
+                    assert!(found.iterations < plain.iterations, "{} took {} iterations on {:?}, and false position {}", name, found.iterations, x, plain.iterations);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn no_sign_change() {
+        let result = illinois(g, (2.0, 3.0), &SolverOptions::default());
+
+        assert!(matches!(result, Err(RootError::NoSignChange { .. })), "{:?}", result);
+        assert!(matches!(ridders(g, (2.0, 3.0), &SolverOptions::default()), Err(RootError::NoSignChange { .. })));
+    }
+}
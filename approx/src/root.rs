@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::Trace;
+
+/// A root found by one of the methods, along with how it was found.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Root<T = f64> {
+    /// The approximation of the root
+    pub x: T,
+    /// The number of iterations the method took
+    pub iterations: usize,
+    /// The number of times `f` was evaluated
+    pub f_evaluations: usize,
+    /// The number of times a derivative of `f` (or the Jacobian) was evaluated
+    pub df_evaluations: usize,
+    /// The history of the method, if [`SolverOptions::trace`](crate::SolverOptions::trace) was set
+    pub trace: Option<Trace<T>>,
+}
@@ -0,0 +1,302 @@
+use crate::{brent, Real, Root, RootError, SolverOptions};
+
+/// A root-finding method that needs a bracket, such as [`bisection`](crate::bisection) or
+/// [`brent`](crate::brent), applied to a function of type `F`.
+///
+/// Implemented for every function and closure with the signature of the
+/// bracketing methods.
+pub trait BracketedMethod<F, R = f64>: Fn(F, (R, R), &SolverOptions) -> Result<Root<R>, RootError<R>> {}
+
+impl<F, R, M> BracketedMethod<F, R> for M where M: Fn(F, (R, R), &SolverOptions) -> Result<Root<R>, RootError<R>> {}
+
+// How much wider the bracket grows on each step of expand_bracket
+const GROWTH: f64 = 1.6;
+
+// How many pieces bracket_and_solve splits the search interval into
+const SCAN_STEPS: usize = 64;
+
+/// Expands the interval `x = (a, b)` outward until `f` changes sign across it.
+///
+/// The endpoint where `|f|` is smaller is pushed out geometrically on each
+/// step, for at most `opts.max_iterations` steps or until `f` stops being
+/// finite. If `a == b` the search starts from a small interval around that
+/// point. Fails with [`RootError::NoSignChange`] on the widest interval
+/// tried if no sign change is found.
+pub fn expand_bracket<R: Real>(mut f: impl FnMut(R) -> R, x: (R, R), opts: &SolverOptions) -> Result<(R, R), RootError<R>> {
+    let (mut a, mut b) = if x.0 < x.1 { x } else { (x.1, x.0) };
+    let growth: R = R::from_f64(GROWTH);
+
+    if a == b {
+        let h: R = R::from_f64(0.1) * a.abs().max(R::from_f64(1.0));
+
+        a -= h;
+        b += h;
+    }
+
+    let mut fa: R = f(a);
+    let mut fb: R = f(b);
+
+    for _ in 0..opts.max_iterations {
+        if !fa.is_finite() || !fb.is_finite() {
+            break;
+        }
+
+        if fa * fb <= R::from_f64(0.0) {
+            return Ok((a, b));
+        }
+
+        if fa.abs() < fb.abs() {
+            a += growth * (a - b);
+            fa = f(a);
+        } else {
+            b += growth * (b - a);
+            fb = f(b);
+        }
+    }
+
+    Err(RootError::NoSignChange { a, fa, b, fb })
+}
+
+/// Splits the interval `x = (a, b)` into `n` equal pieces and returns every
+/// piece that `f` changes sign across, from left to right.
+pub fn scan_brackets<R: Real>(mut f: impl FnMut(R) -> R, x: (R, R), n: usize) -> Vec<(R, R)> {
+    let zero: R = R::from_f64(0.0);
+    let h: R = (x.1 - x.0) / R::from_f64(n as f64);
+    let mut brackets: Vec<(R, R)> = Vec::new();
+    let mut a: R = x.0;
+    let mut fa: R = f(a);
+
+    for i in 1..=n {
+        let b: R = if i == n { x.1 } else { x.0 + R::from_f64(i as f64) * h };
+        let fb: R = f(b);
+
+        // A zero at a shared endpoint only counts for the piece to its right
+        if fa * fb < zero || fa == zero || (fb == zero && i == n) {
+            brackets.push((a, b));
+        }
+
+        a = b;
+        fa = fb;
+    }
+
+    brackets
+}
+
+/// Finds a bracket near `x = (a, b)` and solves it with `method`.
+///
+/// The interval is first scanned for a sign change, then expanded outward
+/// with [`expand_bracket`] if there is none, so a single starting point can
+/// be given as `(x0, x0)`. `method` evaluates `f` at the ends of the bracket
+/// again, which can be avoided by passing a [`Memoized`](crate::Memoized) function.
+///
+/// ```
+/// use approx::{bracket_and_solve, brent, SolverOptions};
+///
+/// fn f(x: f64) -> f64 {
+///     x.powf(3.0) - 4.0 * x.powf(2.0) + 3.0 * x + 1.0
+/// }
+///
+/// if let Ok(root) = bracket_and_solve(f, (3.5, 3.5), brent, &SolverOptions::default()) {
+///     println!("{}", root.x);
+/// }
+/// ```
+pub fn bracket_and_solve<R: Real, F: FnMut(R) -> R>(mut f: F, x: (R, R), method: impl BracketedMethod<F, R>, opts: &SolverOptions) -> Result<Root<R>, RootError<R>> {
+    let scanned: Option<(R, R)> = if x.0 == x.1 { None } else { scan_brackets(&mut f, x, SCAN_STEPS).first().copied() };
+
+    let bracket: (R, R) = match scanned {
+        Some(bracket) => bracket,
+        None => expand_bracket(&mut f, x, opts)?,
+    };
+
+    method(f, bracket, opts)
+}
+
+// How many pieces all_roots splits the interval into
+const ALL_ROOTS_STEPS: usize = 512;
+
+/// How a root found by [`all_roots`] was detected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RootKind {
+    /// `f` changes sign across the root, which was refined with [`brent`].
+    SignChange,
+    /// `f` touches zero without changing sign, so the root was found by minimizing `|f|`.
+    Tangent,
+}
+
+/// A root found by [`all_roots`], along with how it was found.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IntervalRoot<R = f64> {
+    /// The approximation of the root
+    pub x: R,
+    /// The value of `f` at the approximation
+    pub fx: R,
+    /// The number of iterations used to refine the root
+    pub iterations: usize,
+    /// The piece of the interval the root was refined in
+    pub bracket: (R, R),
+    /// How the root was detected
+    pub kind: RootKind,
+}
+
+/// Finds every root of `f` on the interval `[a, b]`.
+///
+/// The interval is split into small pieces; pieces where `f` changes sign
+/// are refined with [`brent`], and local minima of `|f|` where it does not
+/// are refined by minimizing `|f|`, which finds roots where `f` only touches
+/// zero. Roots within the `x` tolerance of each other are merged. Pieces
+/// where `f` changes sign across a pole or a jump instead of a root are
+/// skipped, so the roots found elsewhere are still returned. Roots closer
+/// together than the size of a piece, `(b - a) / 512`, may be missed.
+/// Returns the roots from left to right.
+///
+/// ```
+/// use approx::{all_roots, DoubleDouble, Real, SolverOptions};
+///
+/// // sin(x) on [-1, 7], in double-double precision
+/// let opts = SolverOptions { x_abs_tol: 1e-25, f_tol: 1e-25, ..SolverOptions::default() };
+/// let roots = all_roots(|x: DoubleDouble| x.sin(), DoubleDouble::from(-1.0), DoubleDouble::from(7.0), &opts).unwrap();
+///
+/// assert_eq!(roots.len(), 3);
+/// assert!((roots[2].x - DoubleDouble::pi() * DoubleDouble::from(2.0)).abs() < DoubleDouble::from(1e-25));
+/// ```
+pub fn all_roots<R: Real>(mut f: impl FnMut(R) -> R, a: R, b: R, opts: &SolverOptions) -> Result<Vec<IntervalRoot<R>>, RootError<R>> {
+    let zero: R = R::from_f64(0.0);
+    let n: usize = ALL_ROOTS_STEPS;
+    let h: R = (b - a) / R::from_f64(n as f64);
+    let x: Vec<R> = (0..=n).map(|i| if i == n { b } else { a + R::from_f64(i as f64) * h }).collect();
+    let fx: Vec<R> = x.iter().map(|x| f(*x)).collect();
+    let mut roots: Vec<IntervalRoot<R>> = Vec::new();
+
+    // Sign changes, with a zero at a shared endpoint counting for the piece to its right
+    for i in 0..n {
+        if fx[i] * fx[i + 1] < zero || fx[i] == zero || (fx[i + 1] == zero && i == n - 1) {
+            roots.extend(refine(&mut f, (x[i], fx[i]), (x[i + 1], fx[i + 1]), opts)?);
+        }
+    }
+
+    // Local minima of |f| away from any sign change
+    for k in 1..n {
+        if fx[k - 1] * fx[k] > zero && fx[k] * fx[k + 1] > zero && fx[k].abs() < fx[k - 1].abs() && fx[k].abs() <= fx[k + 1].abs() {
+            let bracket: (R, R) = (x[k - 1], x[k + 1]);
+            let (xm, iterations) = minimize_abs(&mut f, bracket, opts);
+            let fm: R = f(xm);
+
+            if fm.abs() <= R::from_f64(opts.f_tol) {
+                roots.push(IntervalRoot { x: xm, fx: fm, iterations, bracket, kind: RootKind::Tangent });
+            } else if fm * fx[k] < zero {
+                // The minimum dips below zero, so there are two roots too close together for the grid
+                roots.extend(refine(&mut f, (bracket.0, fx[k - 1]), (xm, fm), opts)?);
+                roots.extend(refine(&mut f, (xm, fm), (bracket.1, fx[k + 1]), opts)?);
+            }
+        }
+    }
+
+    roots.sort_by(|r, s| r.x.partial_cmp(&s.x).unwrap_or(std::cmp::Ordering::Equal));
+
+    // Merge roots that are the same to within tolerance, keeping the better approximation
+    let mut merged: Vec<IntervalRoot<R>> = Vec::new();
+    let (x_abs_tol, x_rel_tol): (R, R) = (R::from_f64(opts.x_abs_tol), R::from_f64(opts.x_rel_tol));
+
+    for root in roots {
+        match merged.last_mut() {
+            Some(last) if (root.x - last.x).abs() <= R::from_f64(2.0) * (x_abs_tol + x_rel_tol * root.x.abs()) => {
+                if root.fx.abs() < last.fx.abs() {
+                    *last = root;
+                }
+            },
+            _ => merged.push(root),
+        }
+    }
+
+    Ok(merged)
+}
+
+// Refine the root between a and b with brent, where f(a) = fa and f(b) = fb are already known,
+// so they are not evaluated again. None if f changes sign across a pole or a jump rather than a root
+fn refine<R: Real>(mut f: impl FnMut(R) -> R, (a, fa): (R, R), (b, fb): (R, R), opts: &SolverOptions) -> Result<Option<IntervalRoot<R>>, RootError<R>> {
+    let mut known = |t: R| if t == a { fa } else if t == b { fb } else { f(t) };
+
+    let root: Root<R> = match brent(&mut known, (a, b), opts) {
+        Ok(root) => root,
+        Err(RootError::Discontinuity { .. } | RootError::NonFinite) => return Ok(None),
+        Err(e) => return Err(e),
+    };
+
+    Ok(Some(IntervalRoot { x: root.x, fx: known(root.x), iterations: root.iterations, bracket: (a, b), kind: RootKind::SignChange }))
+}
+
+// Find where |f| is smallest on the interval x using golden section search
+fn minimize_abs<R: Real>(mut f: impl FnMut(R) -> R, x: (R, R), opts: &SolverOptions) -> (R, usize) {
+    let r: R = (R::from_f64(5.0).sqrt() - R::from_f64(1.0)) / R::from_f64(2.0);
+    let (mut a, mut b) = x;
+    let mut c: R = b - r * (b - a);
+    let mut d: R = a + r * (b - a);
+    let mut fc: R = f(c).abs();
+    let mut fd: R = f(d).abs();
+    let mut i: usize = 0;
+
+    while (b - a).abs() > R::from_f64(opts.x_abs_tol) + R::from_f64(opts.x_rel_tol) * c.abs() && i < opts.max_iterations {
+        if fc < fd {
+            (b, d, fd) = (d, c, fc);
+            c = b - r * (b - a);
+            fc = f(c).abs();
+        } else {
+            (a, c, fc) = (c, d, fd);
+            d = a + r * (b - a);
+            fd = f(d).abs();
+        }
+
+        i += 1;
+    }
+
+    (if fc < fd { c } else { d }, i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::f64::consts::PI;
+
+    #[test]
+    fn skips_poles() {
+        let opts: SolverOptions = SolverOptions::default();
+
+        // tan changes sign across its pole at pi / 2, and has a root at pi
+        let roots: Vec<IntervalRoot> = all_roots(|x: f64| x.tan(), 0.5, 3.5, &opts).unwrap();
+
+        assert_eq!(roots.len(), 1, "{:?}", roots);
+        assert!((roots[0].x - PI).abs() < 1e-8);
+
+        let roots: Vec<IntervalRoot> = all_roots(|x: f64| (x - 2.0).sin() / (x - 1.0), 0.0, 4.0, &opts).unwrap();
+
+        assert_eq!(roots.len(), 1, "{:?}", roots);
+        assert!((roots[0].x - 2.0).abs() < 1e-8);
+    }
+
+    #[test]
+    fn tangent_root() {
+        let roots: Vec<IntervalRoot> = all_roots(|x: f64| (x - 1.0).powi(2), 0.0, 2.3, &SolverOptions::default()).unwrap();
+
+        assert_eq!(roots.len(), 1, "{:?}", roots);
+        assert_eq!(roots[0].kind, RootKind::Tangent);
+        assert!((roots[0].x - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn merges_close_roots() {
+        // Roots at 0.99 and 1.01, in different pieces of the scan
+        let f = |x: f64| (x - 1.0).powi(2) - 1e-4;
+
+        let roots: Vec<IntervalRoot> = all_roots(f, 0.0, 2.3, &SolverOptions::default()).unwrap();
+
+        assert_eq!(roots.len(), 2, "{:?}", roots);
+        assert!((roots[0].x - 0.99).abs() < 1e-8 && (roots[1].x - 1.01).abs() < 1e-8);
+
+        // With a loose enough tolerance they are the same root
+        let roots: Vec<IntervalRoot> = all_roots(f, 0.0, 2.3, &SolverOptions { x_abs_tol: 0.011, ..SolverOptions::default() }).unwrap();
+
+        assert_eq!(roots.len(), 1, "{:?}", roots);
+        assert!((roots[0].x - 0.99).abs() < 0.011 || (roots[0].x - 1.01).abs() < 0.011);
+    }
+}
@@ -0,0 +1,249 @@
+// RootError<Vector<f64>> is large, but it is only created once, when a method gives up
+#![allow(clippy::result_large_err)]
+
+use rulinalg::matrix::{BaseMatrix, Matrix};
+use rulinalg::norm::Euclidean;
+use rulinalg::vector::Vector;
+
+use crate::{Root, RootError, Solver, SolverOptions};
+
+/// A system of `n` equations in `n` unknowns, `F(x) = 0`.
+///
+/// Unlike the scalar methods, systems are only solved in `f64`. This is a
+/// choice of interface rather than a limit of the methods: `F` takes and
+/// returns rulinalg's [`Vector`], so systems work directly with the rest of
+/// rulinalg, and a version generic over [`Real`](crate::Real) would need
+/// vector and matrix types of its own.
+pub type System<'a> = dyn Fn(&Vector<f64>) -> Vector<f64> + 'a;
+
+/// The Jacobian matrix of a [`System`], with `J[[i, j]]` the derivative of `F_i` with respect to `x_j`.
+pub type Jacobian<'a> = dyn Fn(&Vector<f64>) -> Matrix<f64> + 'a;
+
+// The most times a step is halved by the line search
+const MAX_HALVINGS: usize = 30;
+
+/// Newton's method for the system `F(x) = 0`, starting from `x`.
+///
+/// Uses `jacobian` if it is given, and otherwise approximates the Jacobian
+/// with forward differences. Each step is damped by a backtracking line
+/// search until it decreases `|F|`, which keeps the method from diverging
+/// when started far from a root. The tolerances in `opts` are applied to the
+/// Euclidean norms of the step and of `F`. Fails with
+/// [`RootError::ZeroDerivative`] if the Jacobian is singular.
+///
+/// ```
+/// use approx::{newton_system, SolverOptions, Vector};
+///
+/// // Where the circle of radius r meets the line y = x
+/// let r: f64 = 2.0;
+/// let f = |x: &Vector<f64>| Vector::new(vec![x[0] * x[0] + x[1] * x[1] - r * r, x[0] - x[1]]);
+///
+/// let root = newton_system(f, None, Vector::new(vec![1.0, 0.5]), &SolverOptions::default()).unwrap();
+///
+/// assert!((root.x[0] - r / 2_f64.sqrt()).abs() < 1e-6);
+/// ```
+pub fn newton_system(f: impl Fn(&Vector<f64>) -> Vector<f64>, jacobian: Option<&Jacobian>, x: Vector<f64>, opts: &SolverOptions) -> Result<Root<Vector<f64>>, RootError<Vector<f64>>> {
+    let s = Solver::new(opts);
+
+    solve_system(&s, &f, x, |x, fx, _| match jacobian {
+        Some(jacobian) => s.eval_derivative(jacobian, x),
+        None => forward_difference(&s, &f, x, fx),
+    })
+}
+
+/// Broyden's ("good") quasi-Newton method for the system `F(x) = 0`, starting from `x`.
+///
+/// Only the first Jacobian is computed, from `jacobian` if it is given and
+/// otherwise with forward differences; after that it is updated with the
+/// change in `F` over each step, so each iteration costs a single
+/// evaluation of `F`. Steps are damped by the same line search as
+/// [`newton_system`].
+pub fn broyden(f: impl Fn(&Vector<f64>) -> Vector<f64>, jacobian: Option<&Jacobian>, x: Vector<f64>, opts: &SolverOptions) -> Result<Root<Vector<f64>>, RootError<Vector<f64>>> {
+    let s = Solver::new(opts);
+    let mut b: Option<Matrix<f64>> = None;
+
+    solve_system(&s, &f, x, |x, fx, last| {
+        let jacobian: Matrix<f64> = match (b.take(), last) {
+            // B += (dF - B dx) dx^T / (dx^T dx)
+            (Some(mut b), Some((dx, df))) => {
+                let u: Vector<f64> = df - &b * dx;
+                let dxdx: f64 = dx.dot(dx);
+
+                for i in 0..b.rows() {
+                    for j in 0..b.cols() {
+                        b[[i, j]] += u[i] * dx[j] / dxdx;
+                    }
+                }
+
+                b
+            },
+            _ => match jacobian {
+                Some(jacobian) => s.eval_derivative(jacobian, x),
+                None => forward_difference(&s, &f, x, fx),
+            },
+        };
+
+        b = Some(jacobian.clone());
+        jacobian
+    })
+}
+
+/// Approximates the Jacobian of `f` at `x` with forward differences.
+pub fn finite_difference_jacobian(f: impl Fn(&Vector<f64>) -> Vector<f64>, x: &Vector<f64>) -> Matrix<f64> {
+    let opts: SolverOptions = SolverOptions::default();
+
+    forward_difference(&Solver::new(&opts), &f, x, &f(x))
+}
+
+// Approximate the Jacobian at x, where f(x) = fx, with a forward difference in each coordinate
+fn forward_difference(s: &Solver<Vector<f64>>, f: &System, x: &Vector<f64>, fx: &Vector<f64>) -> Matrix<f64> {
+    let n: usize = x.size();
+    let mut jacobian: Matrix<f64> = Matrix::zeros(fx.size(), n);
+
+    for j in 0..n {
+        let h: f64 = f64::EPSILON.sqrt() * x[j].abs().max(1.0);
+        let mut xh: Vector<f64> = x.clone();
+
+        xh[j] += h;
+
+        let fxh: Vector<f64> = s.eval(f, &xh);
+
+        for i in 0..fx.size() {
+            jacobian[[i, j]] = (fxh[i] - fx[i]) / h;
+        }
+    }
+
+    jacobian
+}
+
+// Repeatedly take damped Newton steps using the Jacobian returned by jacobian(x, F(x), last step),
+// where the last step is the change in x and in F over the previous iteration
+fn solve_system(
+    s: &Solver<Vector<f64>>,
+    f: &System,
+    mut x: Vector<f64>,
+    mut jacobian: impl FnMut(&Vector<f64>, &Vector<f64>, Option<(&Vector<f64>, &Vector<f64>)>) -> Matrix<f64>,
+) -> Result<Root<Vector<f64>>, RootError<Vector<f64>>> {
+    let mut fx: Vector<f64> = s.eval(f, &x);
+    let mut last: Option<(Vector<f64>, Vector<f64>)> = None;
+    let mut i: usize = 0;
+
+    loop {
+        let j: Matrix<f64> = jacobian(&x, &fx, last.as_ref().map(|(dx, df)| (dx, df)));
+
+        let step: Vector<f64> = match j.solve(-&fx) {
+            Ok(step) if step.iter().all(|d| d.is_finite()) => step,
+            _ => return Err(RootError::ZeroDerivative { x }),
+        };
+
+        // Halve the step until it decreases |F|
+        let norm: f64 = fx.norm(Euclidean);
+        let mut lambda: f64 = 1.0;
+        let mut xn: Vector<f64> = &x + &step;
+        let mut fxn: Vector<f64> = s.eval(f, &xn);
+
+        for _ in 0..MAX_HALVINGS {
+            if fxn.iter().all(|y| y.is_finite()) && fxn.norm(Euclidean) <= (1.0 - 1e-4 * lambda) * norm {
+                break;
+            }
+
+            lambda /= 2.0;
+            xn = &x + &step * lambda;
+            fxn = s.eval(f, &xn);
+        }
+
+        if !xn.iter().chain(fxn.iter()).all(|v| v.is_finite()) {
+            return Err(RootError::NonFinite);
+        }
+
+        s.record(&xn, &fxn, None);
+
+        let dx: Vector<f64> = &xn - &x;
+
+        if s.converged(dx.norm(Euclidean), xn.norm(Euclidean), fxn.norm(Euclidean)) {
+            return Ok(s.finish(xn, i));
+        }
+
+        if let Some(e) = s.limit(i, xn.clone()) {
+            return Err(e);
+        }
+
+        last = Some((dx, &fxn - &fx));
+        x = xn;
+        fx = fxn;
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::f64::consts::SQRT_2;
+
+    // Where the circle of radius 2 meets the line y = x
+    fn circle(x: &Vector<f64>) -> Vector<f64> {
+        Vector::new(vec![x[0] * x[0] + x[1] * x[1] - 4.0, x[0] - x[1]])
+    }
+
+    fn circle_jacobian(x: &Vector<f64>) -> Matrix<f64> {
+        Matrix::new(2, 2, vec![2.0 * x[0], 2.0 * x[1], 1.0, -1.0])
+    }
+
+    fn assert_root(root: &Root<Vector<f64>>) {
+        assert!((root.x[0] - SQRT_2).abs() < 1e-8 && (root.x[1] - SQRT_2).abs() < 1e-8, "{:?}", root.x);
+    }
+
+    #[test]
+    fn newton_with_and_without_jacobian() {
+        let opts: SolverOptions = SolverOptions::default();
+        let exact = newton_system(circle, Some(&circle_jacobian), Vector::new(vec![1.0, 0.5]), &opts).unwrap();
+        let approximate = newton_system(circle, None, Vector::new(vec![1.0, 0.5]), &opts).unwrap();
+
+        assert_root(&exact);
+        assert_root(&approximate);
+        assert!(exact.df_evaluations > 0 && approximate.df_evaluations == 0);
+    }
+
+    #[test]
+    fn newton_damps_far_steps() {
+        // Far from the root a full Newton step overshoots, and the line search keeps it in check
+        let root = newton_system(circle, Some(&circle_jacobian), Vector::new(vec![50.0, -20.0]), &SolverOptions::default()).unwrap();
+
+        assert_root(&root);
+    }
+
+    #[test]
+    fn broyden_converges() {
+        let opts: SolverOptions = SolverOptions::default();
+        let broyden_root = broyden(circle, None, Vector::new(vec![1.0, 0.5]), &opts).unwrap();
+        let newton_root = newton_system(circle, None, Vector::new(vec![1.0, 0.5]), &opts).unwrap();
+
+        assert_root(&broyden_root);
+
+        // Broyden's method only approximates the Jacobian once, so it evaluates F less often
+        assert!(broyden_root.f_evaluations < newton_root.f_evaluations, "{} and {} evaluations", broyden_root.f_evaluations, newton_root.f_evaluations);
+    }
+
+    #[test]
+    fn singular_jacobian() {
+        // Both equations have the same gradient everywhere
+        let f = |x: &Vector<f64>| Vector::new(vec![x[0] + x[1] - 1.0, x[0] + x[1] + 1.0]);
+        let result = newton_system(f, None, Vector::new(vec![0.0, 0.0]), &SolverOptions::default());
+
+        assert!(matches!(result, Err(RootError::ZeroDerivative { .. })), "{:?}", result);
+    }
+
+    #[test]
+    fn finite_differences() {
+        let x: Vector<f64> = Vector::new(vec![0.7, -1.3]);
+        let approximate: Matrix<f64> = finite_difference_jacobian(circle, &x);
+        let exact: Matrix<f64> = circle_jacobian(&x);
+
+        for i in 0..2 {
+            for j in 0..2 {
+                assert!((approximate[[i, j]] - exact[[i, j]]).abs() < 1e-6, "{} and {}", approximate[[i, j]], exact[[i, j]]);
+            }
+        }
+    }
+}
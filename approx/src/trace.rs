@@ -0,0 +1,105 @@
+use std::ops::Sub;
+
+use num_complex::Complex;
+use real::DoubleDouble;
+use rulinalg::vector::Vector;
+use serde::{Deserialize, Serialize};
+
+/// One iteration of a method, as recorded in a [`Trace`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Step<T = f64> {
+    /// The approximation found in this iteration
+    pub x: T,
+    /// The value of `f` at the approximation, or the change `g(x) - x` for fixed-point iteration
+    pub fx: T,
+    /// The change from the previous approximation, or `None` for the first
+    pub step: Option<T>,
+    /// The bracket the approximation was found in, for bracketed methods
+    pub bracket: Option<(T, T)>,
+    /// The number of evaluations of `f` and its derivatives so far
+    pub evaluations: usize,
+}
+
+/// The history of a method, one [`Step`] per iteration.
+///
+/// Recorded when [`SolverOptions::trace`](crate::SolverOptions::trace) is
+/// set, and returned in [`Root::trace`](crate::Root::trace).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Trace<T = f64> {
+    pub steps: Vec<Step<T>>,
+}
+
+/// A value in a [`Trace`] that can be written as a CSV field: a real or
+/// complex number, or a vector.
+///
+/// Traces are written as JSON, or any other format, with serde instead.
+pub trait TraceValue {
+    /// Formats the value as a single CSV field.
+    fn to_csv(&self) -> String;
+}
+
+// Implement TraceValue for a real number type, writing it as it is displayed
+macro_rules! number {
+    ($t:ty) => {
+        impl TraceValue for $t {
+            fn to_csv(&self) -> String {
+                self.to_string()
+            }
+        }
+    };
+}
+
+number!(f32);
+number!(f64);
+number!(DoubleDouble);
+
+impl TraceValue for Complex<f64> {
+    fn to_csv(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl TraceValue for Vector<f64> {
+    fn to_csv(&self) -> String {
+        self.iter().map(|x| x.to_csv()).collect::<Vec<String>>().join(" ")
+    }
+}
+
+impl<T: Clone + Sub<Output = T>> Trace<T> {
+    pub(crate) fn new() -> Self {
+        Trace { steps: Vec::new() }
+    }
+
+    // Record an iteration, measuring the step from the last approximation
+    pub(crate) fn push(&mut self, x: T, fx: T, bracket: Option<(T, T)>, evaluations: usize) {
+        let step: Option<T> = self.steps.last().map(|last| x.clone() - last.x.clone());
+
+        self.steps.push(Step { x, fx, step, bracket, evaluations });
+    }
+}
+
+impl<T: Clone> Trace<T> {
+    /// The approximations found in each iteration.
+    pub fn iterates(&self) -> Vec<T> {
+        self.steps.iter().map(|s| s.x.clone()).collect()
+    }
+}
+
+impl<T: TraceValue> Trace<T> {
+    /// Formats the trace as CSV, with a header and one row per iteration.
+    ///
+    /// Vectors are written as space-separated components, and empty fields
+    /// mean the value was not recorded.
+    pub fn to_csv(&self) -> String {
+        let mut csv: String = String::from("iteration,x,fx,step,a,b,evaluations\n");
+
+        for (i, s) in self.steps.iter().enumerate() {
+            let step: String = s.step.as_ref().map(|d| d.to_csv()).unwrap_or_default();
+            let (a, b) = s.bracket.as_ref().map(|(a, b)| (a.to_csv(), b.to_csv())).unwrap_or_default();
+
+            csv += &format!("{},{},{},{},{},{},{}\n", i, s.x.to_csv(), s.fx.to_csv(), step, a, b, s.evaluations);
+        }
+
+        csv
+    }
+}
@@ -0,0 +1,83 @@
+use clap::{Parser, ValueEnum};
+use differ::*;
+use expr::Expression;
+use serde::Serialize;
+
+// The options of the program
+#[derive(Parser)]
+#[command(about = "Approximates y(3) for y' = t^2 sin(y) + y sin(pi t), y(1) = 1")]
+struct Cli {
+    /// Print the results as JSON or CSV for other programs to read, instead of as text
+    #[arg(long, value_enum)]
+    format: Option<Format>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Json,
+    Csv,
+}
+
+// A method's trajectory with the step size it started from, for JSON output
+#[derive(Serialize)]
+struct Solution<'a> {
+    method: &'a str,
+    h: f64,
+    #[serde(flatten)]
+    trajectory: Trajectory,
+}
+
+//h = 0.1: 3.2914688
+//h = 0.001: 3.2914688
+
+// An approximation of y to eight places, or the reason the method failed
+fn show(result: Result<f64, OdeError>) -> String {
+    match result {
+        Ok(y) => format!("{:.8}", y),
+        Err(e) => e.to_string(),
+    }
+}
+
+fn main() {
+    let cli: Cli = Cli::parse();
+    let g = Expression::parse("t^2 sin(y) + y sin(pi t)", &["t", "y"]).expect("the function is a valid expression").compile();
+    let g = g.bind().expect("the function is in t and y");
+    let f = |t: f64, y: f64| g([t, y]);
+
+    // With --format json or --format csv, print every step of each method for other programs to read
+    if let Some(format) = cli.format {
+        let e: f64 = 10_f64.powf(-6.0);
+        let trajectories = [
+            ("Euler's Method", 0.1, euler_trajectory(f, 1.0, 3.0, 1.0, 0.1)),
+            ("Euler's Method", 0.001, euler_trajectory(f, 1.0, 3.0, 1.0, 0.001)),
+            ("RKF45", 0.1, rkf45_trajectory(f, 1.0, 3.0, 1.0, 0.1, e)),
+            ("RKF45", 0.001, rkf45_trajectory(f, 1.0, 3.0, 1.0, 0.001, e)),
+        ];
+        let solutions: Vec<Solution> = trajectories.into_iter().map(|(method, h, trajectory)| Solution {
+            method,
+            h,
+            trajectory: trajectory.expect("the step sizes are positive and the solution is finite on [1, 3]"),
+        }).collect();
+
+        if format == Format::Json {
+            println!("{}", serde_json::to_string_pretty(&solutions).expect("results can always be written as JSON"));
+        } else {
+            println!("method,h,t,y");
+
+            for s in &solutions {
+                for p in &s.trajectory.points {
+                    println!("{},{},{},{}", s.method, s.h, p.t, p.y);
+                }
+            }
+        }
+
+        return;
+    }
+
+    println!("Method 1: Euler's Method\n------------------------");
+    println!("f({}, {}) = {} with h = {}", 1, 3, show(euler(f, 1.0, 3.0, 1.0, 0.1)), 0.1);
+    println!("f({}, {}) = {} with h = {}", 1, 3, show(euler(f, 1.0, 3.0, 1.0, 0.001)), 0.001); //3.2914688
+    println!("\nMethod 2: RFK45\n---------------");
+    println!("f({}, {}) = {} with h = {}", 1, 3, show(rkf45(f, 1.0, 3.0, 1.0, 0.1, 10_f64.powf(-6.0))), 0.1);
+    println!("f({}, {}) = {} with h = {}", 1, 3, show(rkf45(f, 1.0, 3.0, 1.0, 0.001, 10_f64.powf(-6.0))), 0.001);
+}
@@ -0,0 +1,299 @@
+//! Methods for approximating the solution of an initial value problem
+//! `y' = f(t, y)`, generic over the floating-point type.
+//!
+//! `f` can be any closure, so parameters can be captured:
+//!
+//! ```
+//! use differ::euler;
+//!
+//! // y' = -ky has the solution y(t) = e^(-kt)
+//! for k in [0.5, 1.0, 2.0] {
+//!     let y: f64 = euler(|_, y| -k * y, 0.0, 1.0, 1.0, 0.001).unwrap();
+//!
+//!     assert!((y - (-k).exp()).abs() < 1e-3);
+//! }
+//! ```
+//!
+//! A method that cannot reach the end of the interval returns an
+//! [`OdeError`] rather than looping forever, as RKF45 does when the solution
+//! blows up:
+//!
+//! ```
+//! use differ::rkf45;
+//!
+//! // y' = y^2 with y(0) = 1 has the solution 1 / (1 - t), which is infinite at t = 1
+//! assert!(rkf45(|_, y: f64| y * y, 0.0, 2.0, 1.0, 0.1, 1e-6).is_err());
+//! ```
+
+use std::fmt;
+
+use real::Real;
+use serde::{Deserialize, Serialize};
+
+/// The most steps a method takes before giving up with [`OdeError::MaxSteps`].
+pub const MAX_STEPS: usize = 1_000_000;
+
+/// The reason a method could not approximate the solution at the end of the interval.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum OdeError<R = f64> {
+    /// The step size is not a positive, finite number.
+    StepSize,
+    /// Reaching the end of the interval would take more than [`MAX_STEPS`] steps.
+    MaxSteps,
+    /// RKF45 shrank its step too small to advance past `t`, which usually
+    /// means the solution blows up there.
+    MinimumStep { t: R },
+    /// The approximation of `y` became infinite or NaN at `t`.
+    NonFinite { t: R },
+}
+
+impl<R: fmt::Display> fmt::Display for OdeError<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OdeError::StepSize => write!(f, "The step size must be a positive number"),
+            OdeError::MaxSteps => write!(f, "Reaching the end of the interval would take more than {} steps", MAX_STEPS),
+            OdeError::MinimumStep { t } => write!(f, "The step size became too small to advance past t = {}, where the solution may blow up", t),
+            OdeError::NonFinite { t } => write!(f, "The approximation of y is not a finite number at t = {}", t),
+        }
+    }
+}
+
+impl<R: fmt::Debug + fmt::Display> std::error::Error for OdeError<R> {}
+
+// Check a fixed step size h, and that stepping from t to t_final takes at most MAX_STEPS steps
+fn check_step<R: Real>(t: R, t_final: R, h: R) -> Result<(), OdeError<R>> {
+    if h <= R::from_f64(0.0) || !h.is_finite() {
+        return Err(OdeError::StepSize);
+    }
+
+    if (t_final - t) / h > R::from_f64(MAX_STEPS as f64) {
+        return Err(OdeError::MaxSteps);
+    }
+
+    Ok(())
+}
+
+// How close t has to be to t_final to count as the end of the interval, so a step that only misses
+// t_final by rounding is stretched to land on it rather than followed by a tiny extra step
+fn resolution<R: Real>(t: R, t_final: R) -> R {
+    R::from_f64(16.0) * R::epsilon() * t.abs().max(t_final.abs()).max(t_final - t)
+}
+
+/// A point `(t, y)` of an approximate solution.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Point<R = f64> {
+    pub t: R,
+    pub y: R,
+}
+
+/// An approximate solution at every step a method took, starting with the initial value.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Trajectory<R = f64> {
+    pub points: Vec<Point<R>>,
+}
+
+impl<R: Real> Trajectory<R> {
+    fn new(t: R, y: R) -> Self {
+        Trajectory { points: vec![Point { t, y }] }
+    }
+
+    fn push(&mut self, t: R, y: R) {
+        self.points.push(Point { t, y });
+    }
+
+    /// The last point, which approximates the solution at the end of the interval.
+    ///
+    /// Every method shortens its final step to land exactly on the end:
+    ///
+    /// ```
+    /// use differ::euler_trajectory;
+    ///
+    /// let trajectory = euler_trajectory(|_, y: f64| y, 0.0, 1.0, 1.0, 0.3).unwrap();
+    ///
+    /// assert_eq!(trajectory.last().t, 1.0);
+    /// assert_eq!(trajectory.points.len(), 5);
+    /// ```
+    pub fn last(&self) -> Point<R> {
+        *self.points.last().expect("a trajectory starts with the initial value")
+    }
+
+    /// Formats the trajectory as CSV, with a header and one row per point.
+    pub fn to_csv(&self) -> String {
+        let mut csv: String = String::from("t,y\n");
+
+        for p in &self.points {
+            csv += &format!("{},{}\n", p.t, p.y);
+        }
+
+        csv
+    }
+}
+
+/*
+ * Given: f, the interval [t, t_final], the initial value y and the step size h
+ * Returns: an approximation of y(t_final) using Euler's method
+ */
+pub fn euler<R: Real>(f: impl FnMut(R, R) -> R, t: R, t_final: R, y: R, h: R) -> Result<R, OdeError<R>> {
+    Ok(euler_trajectory(f, t, t_final, y, h)?.last().y)
+}
+
+/*
+ * Given: f, the interval [t, t_final], the initial value y and the step size h
+ * Returns: the approximations of y at each step of Euler's method
+ */
+pub fn euler_trajectory<R: Real>(mut f: impl FnMut(R, R) -> R, t: R, t_final: R, mut y: R, h: R) -> Result<Trajectory<R>, OdeError<R>> {
+    check_step(t, t_final, h)?;
+
+    let mut trajectory: Trajectory<R> = Trajectory::new(t, y);
+    let slack: R = resolution(t, t_final);
+    let mut i: R = R::from_f64(0.0);
+    let mut ti: R = t;
+
+    while ti < t_final {
+        // The last step is shortened to end exactly at t_final
+        let last: bool = t_final - ti <= h + slack;
+        let step: R = if last { t_final - ti } else { h };
+
+        y = y + step * f(ti, y);
+        i += R::from_f64(1.0);
+        ti = if last { t_final } else { t + i * h };
+
+        if !y.is_finite() {
+            return Err(OdeError::NonFinite { t: ti });
+        }
+
+        trajectory.push(ti, y);
+    }
+
+    Ok(trajectory)
+}
+
+/*
+ * Given: f, the interval [t, t_final], the initial value y, the step size h and the weight c
+ * Returns: an approximation of y(t_final) using a second order predictor-corrector method
+ */
+pub fn pc<R: Real>(f: impl FnMut(R, R) -> R, t: R, t_final: R, y: R, h: R, c: R) -> Result<R, OdeError<R>> {
+    Ok(pc_trajectory(f, t, t_final, y, h, c)?.last().y)
+}
+
+/*
+ * Given: f, the interval [t, t_final], the initial value y, the step size h and the weight c
+ * Returns: the approximations of y at each step of the predictor-corrector method
+ */
+pub fn pc_trajectory<R: Real>(mut f: impl FnMut(R, R) -> R, mut t: R, t_final: R, mut y: R, h: R, c: R) -> Result<Trajectory<R>, OdeError<R>> {
+    check_step(t, t_final, h)?;
+
+    let one: R = R::from_f64(1.0);
+    let two: R = R::from_f64(2.0);
+    let mut trajectory: Trajectory<R> = Trajectory::new(t, y);
+    let slack: R = resolution(t, t_final);
+
+    while t < t_final {
+        // The last step is shortened to end exactly at t_final
+        let last: bool = t_final - t <= h + slack;
+        let step: R = if last { t_final - t } else { h };
+        let slope: R = f(t, y);
+
+        y = y + step * (c * slope + (one - c) * f(t + (one / (two * (one - c))) * step, y + (one / (two * (one - c))) * step * slope));
+        t = if last { t_final } else { t + step };
+
+        if !y.is_finite() {
+            return Err(OdeError::NonFinite { t });
+        }
+
+        trajectory.push(t, y);
+    }
+
+    Ok(trajectory)
+}
+
+// One step of size h of the fourth order method of the pair
+fn rkf4<R: Real>(f: &mut impl FnMut(R, R) -> R, t: R, y: R, h: R) -> R {
+    let c = R::from_f64;
+    let mut k: [R; 6] = [c(0.0); 6];
+
+    k[0] = f(t, y);
+    k[1] = f(t + (h / c(5.0)), y + h * k[0] / c(5.0));
+    k[2] = f(t + c(3.0) * h / c(10.0), y + c(3.0) * h * k[1] / c(40.0) + c(9.0) * h * k[1] / c(40.0));
+    k[3] = f(t + c(4.0) * h / c(5.0), y + c(44.0) * h * k[0] / c(45.0) - c(56.0) * h * k[1] / c(15.0) + c(32.0) * h * k[2] / c(9.0));
+    k[4] = f(t + c(8.0) * h / c(9.0), y + c(19372.0) * h * k[0] / c(6561.0) - c(25360.0) * h * k[1] / c(2187.0) + c(64448.0) * h * k[2] / c(6561.0) - c(212.0) * h * k[3] / c(729.0));
+    k[5] = f(t + h, y + c(9017.0) * h * k[0] / c(3168.0) - c(355.0) * h * k[1] / c(33.0) + c(46732.0) * h * k[3] / c(5247.0) + c(49.0) * h * k[3] / c(176.0) - c(5103.0) * h * k[4] / c(18656.0));
+
+    y + h * (c(35.0) * k[0] / c(384.0) + c(500.0) * k[2] / c(1113.0) + c(125.0) * k[3] / c(192.0) - c(2187.0) * k[4] / c(6784.0) + c(11.0) * k[5] / c(84.0))
+}
+
+// One step of size h of the fifth order method of the pair
+fn rkf5<R: Real>(f: &mut impl FnMut(R, R) -> R, t: R, y: R, h: R) -> R {
+    let c = R::from_f64;
+    let mut k: [R; 6] = [c(0.0); 6];
+
+    k[0] = f(t, y);
+    k[1] = f(t + h / c(5.0), y + h * k[0] / c(5.0));
+    k[2] = f(t + c(3.0) * h / c(10.0), y + c(3.0) * h * k[0] / c(40.0) + c(9.0) * h * k[1] / c(40.0));
+    k[3] = f(t + c(4.0) * h / c(5.0), y + c(44.0) * h * k[0] / c(45.0) - c(56.0) * h * k[1] / c(15.0) + c(32.0) * h * k[2] / c(9.0));
+    k[4] = f(t + c(8.0) * h / c(9.0), y + c(19372.0) * h * k[0] / c(6561.0) - c(25360.0) * h * k[1] / c(2187.0) + c(64448.0) * h * k[2] / c(6561.0) - c(212.0) * h * k[3] / c(729.0));
+    k[5] = f(t + h, y + c(9017.0) * h * k[0] / c(3168.0) - c(355.0) * h * k[1] / c(33.0) + c(46732.0) * h * k[2] / c(5247.0) + c(49.0) * h * k[3] / c(176.0) - c(5103.0) * h * k[4] / c(18656.0));
+
+    y + h * (c(35.0) * k[0] / c(384.0) + c(500.0) * k[2] / c(1113.0) + c(125.0) * k[3] / c(192.0) - c(2187.0) * k[4] / c(6784.0) + c(11.0) * k[5] / c(84.0))
+}
+
+/*
+ * Given: f, the interval [t, t_final], the initial value y, the initial step size h and the tolerance e
+ * Returns: an approximation of y(t_final) using the Runge-Kutta-Fehlberg method,
+ * halving or doubling h to keep the error of each step near e
+ */
+pub fn rkf45<R: Real>(f: impl FnMut(R, R) -> R, t: R, t_final: R, y: R, h: R, e: R) -> Result<R, OdeError<R>> {
+    Ok(rkf45_trajectory(f, t, t_final, y, h, e)?.last().y)
+}
+
+/*
+ * Given: f, the interval [t, t_final], the initial value y, the initial step size h and the tolerance e
+ * Returns: the approximations of y at each step the Runge-Kutta-Fehlberg method accepted
+ */
+pub fn rkf45_trajectory<R: Real>(mut f: impl FnMut(R, R) -> R, mut t: R, t_final: R, mut y: R, mut h: R, e: R) -> Result<Trajectory<R>, OdeError<R>> {
+    if h <= R::from_f64(0.0) || !h.is_finite() {
+        return Err(OdeError::StepSize);
+    }
+
+    let two: R = R::from_f64(2.0);
+    let mut trajectory: Trajectory<R> = Trajectory::new(t, y);
+
+    // A step this small would barely change t anywhere on the interval, so halving it any
+    // further would only step in place
+    let h_min: R = resolution(t, t_final);
+
+    while t < t_final {
+        if trajectory.points.len() > MAX_STEPS {
+            return Err(OdeError::MaxSteps);
+        }
+
+        // The last step is shortened to end exactly at t_final
+        let last: bool = t_final - t <= h + h_min;
+        let step: R = if last { t_final - t } else { h };
+
+        let y1 = rkf4(&mut f, t, y, step);
+        let y2 = rkf5(&mut f, t, y, step);
+
+        let error = (y1 - y2).abs();
+
+        if R::from_f64(0.25) * step * e > error {
+            t = if last { t_final } else { t + step };
+            y = y2;
+            trajectory.push(t, y);
+            h *= two;
+        // A NaN error, from a step past where the solution blows up, also shrinks the step
+        } else if error > step * e || error.is_nan() {
+            h = step / two;
+
+            if h < h_min {
+                return Err(OdeError::MinimumStep { t });
+            }
+        } else {
+            t = if last { t_final } else { t + step };
+            y = y2;
+            trajectory.push(t, y);
+        }
+    }
+
+    Ok(trajectory)
+}
@@ -0,0 +1,225 @@
+use std::fmt;
+
+use real::Real;
+
+use crate::{Constant, Expr, Func};
+
+// One instruction of a compiled expression, which works on a stack of values
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Op {
+    Number(f64),
+    Constant(Constant),
+    Variable(usize),
+    Neg,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Pow,
+    Call(Func),
+}
+
+/// An expression compiled into a flat list of stack operations, which can
+/// be evaluated in any [`Real`] type without walking the expression tree.
+///
+/// Created by [`Expression::compile`](crate::Expression::compile). Pass it
+/// to a method as a closure, after checking with [`bind`](Compiled::bind)
+/// that it takes no more variables than the closure has arguments:
+///
+/// ```
+/// use expr::Expression;
+///
+/// let f = Expression::parse("t^2 sin(y) + y sin(pi t)", &["t", "y"]).unwrap().compile();
+/// let f = f.bind::<f64, 2>().unwrap();
+/// let g = |t: f64, y: f64| f([t, y]);
+///
+/// assert_eq!(g(0.5, 2.0), 0.25 * 2_f64.sin() + 2.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Compiled {
+    ops: Vec<Op>,
+    // The most values on the stack at once
+    depth: usize,
+    variables: usize,
+}
+
+/// Fewer values were given than the compiled expression has variables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArityError {
+    pub expected: usize,
+    pub given: usize,
+}
+
+impl fmt::Display for ArityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The expression takes {} variables but {} values were given", self.expected, self.given)
+    }
+}
+
+impl std::error::Error for ArityError {}
+
+// Append the operations evaluating expr in postfix order
+fn emit(expr: &Expr, ops: &mut Vec<Op>) {
+    match expr {
+        Expr::Number(x) => ops.push(Op::Number(*x)),
+        Expr::Constant(c) => ops.push(Op::Constant(*c)),
+        Expr::Variable(i) => ops.push(Op::Variable(*i)),
+        Expr::Neg(a) => {
+            emit(a, ops);
+            ops.push(Op::Neg);
+        },
+        Expr::Call(func, a) => {
+            emit(a, ops);
+            ops.push(Op::Call(*func));
+        },
+        Expr::Add(a, b) | Expr::Sub(a, b) | Expr::Mul(a, b) | Expr::Div(a, b) | Expr::Pow(a, b) => {
+            emit(a, ops);
+            emit(b, ops);
+            ops.push(match expr {
+                Expr::Add(..) => Op::Add,
+                Expr::Sub(..) => Op::Sub,
+                Expr::Mul(..) => Op::Mul,
+                Expr::Div(..) => Op::Div,
+                _ => Op::Pow,
+            });
+        },
+    }
+}
+
+impl Compiled {
+    pub(crate) fn new(expr: &Expr, variables: usize) -> Compiled {
+        let mut ops: Vec<Op> = Vec::new();
+
+        emit(expr, &mut ops);
+
+        // Values push one value, and binary operations pop two and push one
+        let mut size: usize = 0;
+        let mut depth: usize = 0;
+
+        // An expression tree built by hand may refer to more variables than it was given names for
+        let variables: usize = ops.iter().fold(variables, |n, op| match op {
+            Op::Variable(i) => n.max(i + 1),
+            _ => n,
+        });
+
+        for op in &ops {
+            match op {
+                Op::Number(_) | Op::Constant(_) | Op::Variable(_) => size += 1,
+                Op::Add | Op::Sub | Op::Mul | Op::Div | Op::Pow => size -= 1,
+                Op::Neg | Op::Call(_) => {},
+            }
+
+            depth = depth.max(size);
+        }
+
+        Compiled { ops, depth, variables }
+    }
+
+    /// The number of variables the expression takes.
+    pub fn variables(&self) -> usize {
+        self.variables
+    }
+
+    /// Evaluates the expression, with `values[i]` the value of the `i`th
+    /// variable. Fails if fewer values are given than the expression has
+    /// variables.
+    pub fn eval<R: Real>(&self, values: &[R]) -> Result<R, ArityError> {
+        self.check(values.len())?;
+
+        Ok(self.run(values))
+    }
+
+    /// Checks once that `N` values are enough for the expression's
+    /// variables, and returns a function evaluating it at an array of `N`
+    /// values, which cannot fail.
+    pub fn bind<R: Real, const N: usize>(&self) -> Result<impl Fn([R; N]) -> R + '_, ArityError> {
+        self.check(N)?;
+
+        Ok(move |values: [R; N]| self.run(&values))
+    }
+
+    // Ensure n values are enough for the variables of the expression
+    fn check(&self, n: usize) -> Result<(), ArityError> {
+        if n < self.variables {
+            Err(ArityError { expected: self.variables, given: n })
+        } else {
+            Ok(())
+        }
+    }
+
+    // Evaluate the expression at values, which has already been checked to hold every variable
+    fn run<R: Real>(&self, values: &[R]) -> R {
+        let mut stack: Vec<R> = Vec::with_capacity(self.depth);
+
+        for op in &self.ops {
+            let value: R = match op {
+                Op::Number(x) => R::from_f64(*x),
+                Op::Constant(Constant::Pi) => R::pi(),
+                Op::Constant(Constant::E) => R::from_f64(1.0).exp(),
+                Op::Variable(i) => values[*i],
+                Op::Neg => -pop(&mut stack),
+                Op::Call(func) => call(*func, pop(&mut stack)),
+                _ => {
+                    let b: R = pop(&mut stack);
+                    let a: R = pop(&mut stack);
+
+                    match op {
+                        Op::Add => a + b,
+                        Op::Sub => a - b,
+                        Op::Mul => a * b,
+                        Op::Div => a / b,
+                        _ => a.powf(b),
+                    }
+                },
+            };
+
+            stack.push(value);
+        }
+
+        pop(&mut stack)
+    }
+}
+
+// The stack always holds the operands, since the operations were emitted in postfix order
+fn pop<R: Real>(stack: &mut Vec<R>) -> R {
+    stack.pop().expect("compiled expressions are well formed")
+}
+
+// Evaluate a built-in function
+fn call<R: Real>(func: Func, x: R) -> R {
+    match func {
+        Func::Sin => x.sin(),
+        Func::Cos => x.cos(),
+        Func::Tan => x.tan(),
+        Func::Exp => x.exp(),
+        Func::Ln => x.ln(),
+        Func::Sqrt => x.sqrt(),
+        Func::Abs => x.abs(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Expr, Expression};
+
+    use super::*;
+
+    #[test]
+    fn too_few_values() {
+        let f: Compiled = Expression::parse("t y", &["t", "y"]).unwrap().compile();
+
+        assert_eq!(f.eval(&[2.0, 3.0]), Ok(6.0));
+        assert_eq!(f.eval(&[2.0]), Err(ArityError { expected: 2, given: 1 }));
+        assert!(f.bind::<f64, 1>().is_err());
+        assert_eq!(f.bind::<f64, 2>().unwrap()([2.0, 3.0]), 6.0);
+    }
+
+    #[test]
+    fn variables_beyond_names() {
+        // A tree built by hand that refers to a third variable while naming only one
+        let f: Compiled = Expression::new(Expr::Variable(2), &["x"]).compile();
+
+        assert_eq!(f.eval(&[1.0]), Err(ArityError { expected: 3, given: 1 }));
+        assert_eq!(f.eval(&[1.0, 2.0, 3.0]), Ok(3.0));
+    }
+}
@@ -0,0 +1,140 @@
+use crate::{Expr, Func};
+
+// Whether expr depends on the variable at index v
+fn depends(expr: &Expr, v: usize) -> bool {
+    match expr {
+        Expr::Number(_) | Expr::Constant(_) => false,
+        Expr::Variable(i) => *i == v,
+        Expr::Neg(a) | Expr::Call(_, a) => depends(a, v),
+        Expr::Add(a, b) | Expr::Sub(a, b) | Expr::Mul(a, b) | Expr::Div(a, b) | Expr::Pow(a, b) => depends(a, v) || depends(b, v),
+    }
+}
+
+fn pow(a: Expr, b: Expr) -> Expr {
+    Expr::Pow(Box::new(a), Box::new(b))
+}
+
+fn call(func: Func, a: Expr) -> Expr {
+    Expr::Call(func, Box::new(a))
+}
+
+// The derivative of expr with respect to the variable at index v, before it is simplified
+pub(crate) fn derivative(expr: &Expr, v: usize) -> Expr {
+    let d = |e: &Expr| derivative(e, v);
+
+    match expr {
+        Expr::Number(_) | Expr::Constant(_) => Expr::Number(0.0),
+        Expr::Variable(i) => Expr::Number(if *i == v { 1.0 } else { 0.0 }),
+        Expr::Neg(a) => -d(a),
+        Expr::Add(a, b) => d(a) + d(b),
+        Expr::Sub(a, b) => d(a) - d(b),
+        // (ab)' = a'b + ab'
+        Expr::Mul(a, b) => d(a) * (**b).clone() + (**a).clone() * d(b),
+        Expr::Div(a, b) => {
+            let (numerator, denominator) = ((**a).clone(), (**b).clone());
+
+            if !depends(b, v) {
+                d(a) / denominator
+            } else if !depends(a, v) {
+                // (c/b)' = -cb' / b^2
+                -(numerator * d(b)) / pow(denominator, Expr::Number(2.0))
+            } else {
+                // (a/b)' = (a'b - ab') / b^2
+                (d(a) * denominator.clone() - numerator * d(b)) / pow(denominator, Expr::Number(2.0))
+            }
+        },
+        Expr::Pow(a, b) => {
+            let (base, exponent) = ((**a).clone(), (**b).clone());
+
+            if !depends(b, v) {
+                // (a^n)' = n a^(n - 1) a'
+                exponent.clone() * pow(base, exponent - Expr::Number(1.0)) * d(a)
+            } else if !depends(a, v) {
+                // (c^b)' = c^b ln(c) b'
+                expr.clone() * call(Func::Ln, base) * d(b)
+            } else {
+                // (a^b)' = a^b (b' ln(a) + b a' / a)
+                expr.clone() * (d(b) * call(Func::Ln, base.clone()) + exponent * d(a) / base)
+            }
+        },
+        // The chain rule, f(a)' = f'(a) a'
+        Expr::Call(func, a) => {
+            let inner: Expr = (**a).clone();
+            let outer: Expr = match func {
+                Func::Sin => call(Func::Cos, inner),
+                Func::Cos => -call(Func::Sin, inner),
+                Func::Tan => Expr::Number(1.0) / pow(call(Func::Cos, inner), Expr::Number(2.0)),
+                Func::Exp => expr.clone(),
+                Func::Ln => Expr::Number(1.0) / inner,
+                Func::Sqrt => Expr::Number(1.0) / (Expr::Number(2.0) * expr.clone()),
+                Func::Abs => inner / expr.clone(),
+            };
+
+            outer * d(a)
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Expression;
+
+    // Whether the derivative of source with respect to each variable matches a central
+    // difference at each point
+    fn matches_differences(source: &str, variables: &[&str], points: &[&[f64]]) {
+        let f = Expression::parse(source, variables).unwrap();
+        let g = f.compile();
+        let h: f64 = 1e-5;
+
+        for (v, variable) in variables.iter().enumerate() {
+            let derivative = f.derivative(variable);
+            let d = derivative.compile();
+
+            for point in points {
+                let (mut above, mut below): (Vec<f64>, Vec<f64>) = (point.to_vec(), point.to_vec());
+
+                above[v] += h;
+                below[v] -= h;
+
+                let difference: f64 = (g.eval(&above).unwrap() - g.eval(&below).unwrap()) / (2.0 * h);
+                let exact: f64 = d.eval(point).unwrap();
+
+                assert!((exact - difference).abs() <= 1e-6 * exact.abs().max(1.0), "d/d{} {} = {} is {} at {:?}, but differences give {}", variable, source, derivative, exact, point, difference);
+            }
+        }
+    }
+
+    #[test]
+    fn derivatives_match_differences() {
+        let points: [&[f64]; 5] = [&[0.3], &[0.7], &[1.3], &[2.1], &[2.7]];
+        let sources: [&str; 15] = [
+            "x^3 - 4x^2 + 3x + 1",
+            "exp(x) (1 - 2cos(x))",
+            "3/(1+x^4)",
+            "e^(-x^2) * (x^2 + 1)",
+            "ln(x^2)",
+            "ln(x) / ln(2)",
+            "sin(x)^2 / x",
+            "tan(x / 2)",
+            "sqrt(x) x",
+            "x^x",
+            "2^x",
+            "abs(x - 1)",
+            "(x^2)^3",
+            "(x^0.5)^2",
+            "x / x^2 - 1 / (x^-1)",
+        ];
+
+        for source in sources {
+            matches_differences(source, &["x"], &points);
+        }
+    }
+
+    #[test]
+    fn partial_derivatives_match_differences() {
+        let points: [&[f64]; 3] = [&[1.0, 1.0], &[1.5, -0.5], &[2.5, 3.0]];
+
+        matches_differences("t^2 sin(y) + y sin(pi t)", &["t", "y"], &points);
+        matches_differences("t / y + y^t", &["t", "y"], &[&[1.0, 1.0], &[1.5, 0.5], &[2.5, 3.0]]);
+    }
+}
@@ -0,0 +1,60 @@
+use std::fmt;
+
+use crate::{Constant, Expr, Expression};
+
+// How tightly each kind of node binds, so parentheses are only written where they are needed
+fn precedence(expr: &Expr) -> u8 {
+    match expr {
+        Expr::Add(..) | Expr::Sub(..) => 1,
+        Expr::Mul(..) | Expr::Div(..) => 2,
+        Expr::Neg(_) => 3,
+        Expr::Number(x) if *x < 0.0 => 3,
+        Expr::Pow(..) => 4,
+        _ => 5,
+    }
+}
+
+// Write expr, in parentheses if it binds less tightly than min
+fn write(f: &mut fmt::Formatter<'_>, expr: &Expr, variables: &[String], min: u8) -> fmt::Result {
+    if precedence(expr) < min {
+        write!(f, "(")?;
+        write(f, expr, variables, 0)?;
+        return write!(f, ")");
+    }
+
+    // Write a binary operation, where the right operand of -, * and / binds one level tighter, since
+    // the operators are left associative and a * (b / c) is not the tree (a * b) / c
+    let mut binary = |a: &Expr, op: &str, b: &Expr, p: u8, right: u8| -> fmt::Result {
+        write(f, a, variables, p)?;
+        write!(f, "{}", op)?;
+        write(f, b, variables, right)
+    };
+
+    match expr {
+        Expr::Number(x) => write!(f, "{}", x),
+        Expr::Constant(Constant::Pi) => write!(f, "pi"),
+        Expr::Constant(Constant::E) => write!(f, "e"),
+        Expr::Variable(i) => write!(f, "{}", variables[*i]),
+        Expr::Add(a, b) => binary(a, " + ", b, 1, 1),
+        Expr::Sub(a, b) => binary(a, " - ", b, 1, 2),
+        Expr::Mul(a, b) => binary(a, " * ", b, 2, 3),
+        Expr::Div(a, b) => binary(a, " / ", b, 2, 3),
+        // Powers are right associative, and a negative base needs parentheses
+        Expr::Pow(a, b) => binary(a, "^", b, 5, 3),
+        Expr::Neg(a) => {
+            write!(f, "-")?;
+            write(f, a, variables, 3)
+        },
+        Expr::Call(func, a) => {
+            write!(f, "{}(", func.name())?;
+            write(f, a, variables, 0)?;
+            write!(f, ")")
+        },
+    }
+}
+
+impl fmt::Display for Expression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write(f, &self.expr, &self.variables, 0)
+    }
+}
@@ -0,0 +1,209 @@
+/*
+ * Written By: Alex Jackson (asj18a@acu.edu)
+ *
+ * Mathematical expressions supplied at runtime:
+ *  - Parsing expressions such as "x^3 - 4x^2 + 3x + 1" into expression trees
+ *  - Compiling expression trees into functions that evaluate in any Real type
+ *  - Differentiating and simplifying expression trees symbolically
+ *
+ *  */
+
+//! Parses mathematical expressions from strings, so the function a method
+//! works on can be supplied at runtime.
+//!
+//! An expression may use the variables it is parsed with, numbers, the
+//! constants `pi` and `e`, the operators `+ - * / ^`, parentheses and the
+//! functions `sin`, `cos`, `tan`, `exp`, `ln`, `sqrt` and `abs`. Factors
+//! written next to each other are multiplied, so `4x^2` is `4 * x^2` and
+//! `2 sin(pi t)` is `2 * sin(pi * t)`. Compile an [`Expression`] to
+//! evaluate it:
+//!
+//! ```
+//! use expr::Expression;
+//!
+//! let f = Expression::parse("x^3 - 4x^2 + 3x + 1", &["x"]).unwrap().compile();
+//!
+//! assert_eq!(f.eval(&[2.0]), Ok(-1.0));
+//! ```
+//!
+//! Derivatives are found symbolically, so they can be printed as well as
+//! evaluated:
+//!
+//! ```
+//! use expr::Expression;
+//!
+//! let f = Expression::parse("x^3 - 4x^2 + 3x + 1", &["x"]).unwrap();
+//!
+//! assert_eq!(f.derivative("x").to_string(), "3 * x^2 - 8 * x + 3");
+//! ```
+
+mod compile;
+mod derivative;
+mod display;
+mod parse;
+mod simplify;
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+pub use crate::compile::{ArityError, Compiled};
+pub use crate::parse::ParseError;
+
+/// A built-in function of one argument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Func {
+    Sin,
+    Cos,
+    Tan,
+    Exp,
+    Ln,
+    Sqrt,
+    Abs,
+}
+
+impl Func {
+    /// The function with the given name, as it is written in an expression.
+    pub fn from_name(name: &str) -> Option<Func> {
+        match name {
+            "sin" => Some(Func::Sin),
+            "cos" => Some(Func::Cos),
+            "tan" => Some(Func::Tan),
+            "exp" => Some(Func::Exp),
+            "ln" => Some(Func::Ln),
+            "sqrt" => Some(Func::Sqrt),
+            "abs" => Some(Func::Abs),
+            _ => None,
+        }
+    }
+
+    /// The name of the function, as it is written in an expression.
+    pub fn name(self) -> &'static str {
+        match self {
+            Func::Sin => "sin",
+            Func::Cos => "cos",
+            Func::Tan => "tan",
+            Func::Exp => "exp",
+            Func::Ln => "ln",
+            Func::Sqrt => "sqrt",
+            Func::Abs => "abs",
+        }
+    }
+}
+
+/// A named mathematical constant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Constant {
+    Pi,
+    E,
+}
+
+/// A node of an expression tree.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Number(f64),
+    Constant(Constant),
+    /// The variable at this index in the expression's list of variables
+    Variable(usize),
+    Neg(Box<Expr>),
+    Add(Box<Expr>, Box<Expr>),
+    Sub(Box<Expr>, Box<Expr>),
+    Mul(Box<Expr>, Box<Expr>),
+    Div(Box<Expr>, Box<Expr>),
+    Pow(Box<Expr>, Box<Expr>),
+    Call(Func, Box<Expr>),
+}
+
+impl Add for Expr {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Expr::Add(Box::new(self), Box::new(rhs))
+    }
+}
+
+impl Sub for Expr {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Expr::Sub(Box::new(self), Box::new(rhs))
+    }
+}
+
+impl Mul for Expr {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Expr::Mul(Box::new(self), Box::new(rhs))
+    }
+}
+
+impl Div for Expr {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Expr::Div(Box::new(self), Box::new(rhs))
+    }
+}
+
+impl Neg for Expr {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Expr::Neg(Box::new(self))
+    }
+}
+
+/// A parsed expression in one or more variables.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expression {
+    /// The expression tree
+    pub expr: Expr,
+    variables: Vec<String>,
+}
+
+impl Expression {
+    /// Parses `source` as an expression in the given variables.
+    ///
+    /// The variables are referred to by their position in `variables`, so
+    /// for `&["t", "y"]` the value of `t` is passed first when evaluating.
+    pub fn parse(source: &str, variables: &[&str]) -> Result<Expression, ParseError> {
+        let expr: Expr = parse::parse(source, variables)?;
+
+        Ok(Expression { expr, variables: variables.iter().map(|v| v.to_string()).collect() })
+    }
+
+    /// Wraps an expression tree over the given variables.
+    pub fn new(expr: Expr, variables: &[&str]) -> Expression {
+        Expression { expr, variables: variables.iter().map(|v| v.to_string()).collect() }
+    }
+
+    /// The names of the variables, in the order their values are passed.
+    pub fn variables(&self) -> &[String] {
+        &self.variables
+    }
+
+    /// Compiles the expression into a function that can be evaluated quickly and repeatedly.
+    pub fn compile(&self) -> Compiled {
+        Compiled::new(&self.expr, self.variables.len())
+    }
+
+    /// The simplified derivative with respect to `variable`. The derivative
+    /// with respect to a name that is not one of the variables is zero.
+    pub fn derivative(&self, variable: &str) -> Expression {
+        let expr: Expr = match self.variables.iter().position(|v| v == variable) {
+            Some(i) => simplify::simplify(&derivative::derivative(&self.expr, i)),
+            None => Expr::Number(0.0),
+        };
+
+        Expression { expr, variables: self.variables.clone() }
+    }
+
+    /// The expression with constants folded and identities such as
+    /// `0 + x = x` and `1 * x = x` applied.
+    ///
+    /// The identities hold wherever every subexpression is finite and every
+    /// divisor is nonzero, so `x / x` simplifies to `1` although it is
+    /// undefined at 0. A literal `0 / 0` is left as it is.
+    pub fn simplify(&self) -> Expression {
+        Expression { expr: simplify::simplify(&self.expr), variables: self.variables.clone() }
+    }
+}
@@ -0,0 +1,394 @@
+use std::fmt;
+
+use crate::{Constant, Expr, Func};
+
+/// The reason an expression could not be parsed. Positions are byte
+/// offsets into the source.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    /// A character that cannot appear in an expression.
+    UnexpectedCharacter { character: char, position: usize },
+    /// A token that cannot appear where it was found, such as an operator with no operand.
+    UnexpectedToken { token: String, position: usize },
+    /// The expression ended where an operand or closing parenthesis was expected.
+    UnexpectedEnd,
+    /// A name that is not a variable, constant or function.
+    UnknownName { name: String, position: usize },
+    /// A function name that is not followed by its argument in parentheses.
+    MissingArgument { name: String, position: usize },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnexpectedCharacter { character, position } => write!(f, "Unexpected character '{}' at position {}", character, position),
+            ParseError::UnexpectedToken { token, position } => write!(f, "Unexpected '{}' at position {}", token, position),
+            ParseError::UnexpectedEnd => write!(f, "Unexpected end of expression"),
+            ParseError::UnknownName { name, position } => write!(f, "Unknown variable or function '{}' at position {}", name, position),
+            ParseError::MissingArgument { name, position } => write!(f, "Function '{}' at position {} needs its argument in parentheses", name, position),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Name(String),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Caret,
+    Open,
+    Close,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Number(x) => write!(f, "{}", x),
+            Token::Name(name) => write!(f, "{}", name),
+            Token::Plus => write!(f, "+"),
+            Token::Minus => write!(f, "-"),
+            Token::Star => write!(f, "*"),
+            Token::Slash => write!(f, "/"),
+            Token::Caret => write!(f, "^"),
+            Token::Open => write!(f, "("),
+            Token::Close => write!(f, ")"),
+        }
+    }
+}
+
+// Split the source into tokens, each with its position
+fn tokenize(source: &str) -> Result<Vec<(Token, usize)>, ParseError> {
+    let chars: Vec<(usize, char)> = source.char_indices().collect();
+    let mut tokens: Vec<(Token, usize)> = Vec::new();
+    let mut i: usize = 0;
+
+    while i < chars.len() {
+        let (position, c) = chars[i];
+        let digit = |k: usize| chars.get(k).is_some_and(|(_, c)| c.is_ascii_digit());
+
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        let token: Token = if c.is_ascii_digit() || (c == '.' && digit(i + 1)) {
+            let start: usize = i;
+
+            while digit(i) || chars.get(i).is_some_and(|(_, c)| *c == '.') {
+                i += 1;
+            }
+
+            // An exponent, as long as it is not the constant e
+            if chars.get(i).is_some_and(|(_, c)| *c == 'e' || *c == 'E') {
+                let sign: bool = chars.get(i + 1).is_some_and(|(_, c)| *c == '+' || *c == '-');
+
+                if digit(i + 1) || (sign && digit(i + 2)) {
+                    i += if sign { 2 } else { 1 };
+
+                    while digit(i) {
+                        i += 1;
+                    }
+                }
+            }
+
+            let end: usize = chars.get(i).map_or(source.len(), |(p, _)| *p);
+            let text: &str = &source[chars[start].0..end];
+
+            Token::Number(text.parse().map_err(|_| ParseError::UnexpectedToken { token: text.to_string(), position })?)
+        } else if c.is_alphabetic() {
+            let mut name: String = String::new();
+
+            while let Some((_, c)) = chars.get(i).filter(|(_, c)| c.is_alphanumeric() || *c == '_') {
+                name.push(*c);
+                i += 1;
+            }
+
+            Token::Name(name)
+        } else {
+            i += 1;
+
+            match c {
+                '+' => Token::Plus,
+                '-' => Token::Minus,
+                '*' => Token::Star,
+                '/' => Token::Slash,
+                '^' => Token::Caret,
+                '(' => Token::Open,
+                ')' => Token::Close,
+                _ => return Err(ParseError::UnexpectedCharacter { character: c, position }),
+            }
+        };
+
+        tokens.push((token, position));
+    }
+
+    Ok(tokens)
+}
+
+// A recursive descent parser, with one method for each level of precedence
+struct Parser<'a> {
+    tokens: Vec<(Token, usize)>,
+    next: usize,
+    variables: &'a [&'a str],
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.next).map(|(token, _)| token)
+    }
+
+    // Consume the next token if it is the one given
+    fn accept(&mut self, token: &Token) -> bool {
+        if self.peek() == Some(token) {
+            self.next += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    // The error for the next token, which cannot appear where it is
+    fn unexpected(&self) -> ParseError {
+        match self.tokens.get(self.next) {
+            Some((token, position)) => ParseError::UnexpectedToken { token: token.to_string(), position: *position },
+            None => ParseError::UnexpectedEnd,
+        }
+    }
+
+    // expression := term (('+' | '-') term)*
+    fn expression(&mut self) -> Result<Expr, ParseError> {
+        let mut left: Expr = self.term()?;
+
+        loop {
+            if self.accept(&Token::Plus) {
+                left = Expr::Add(Box::new(left), Box::new(self.term()?));
+            } else if self.accept(&Token::Minus) {
+                left = Expr::Sub(Box::new(left), Box::new(self.term()?));
+            } else {
+                return Ok(left);
+            }
+        }
+    }
+
+    // term := unary (('*' | '/') unary | power)*, where a power directly after a factor multiplies it
+    fn term(&mut self) -> Result<Expr, ParseError> {
+        let mut left: Expr = self.unary()?;
+
+        loop {
+            if self.accept(&Token::Star) {
+                left = Expr::Mul(Box::new(left), Box::new(self.unary()?));
+            } else if self.accept(&Token::Slash) {
+                left = Expr::Div(Box::new(left), Box::new(self.unary()?));
+            } else if matches!(self.peek(), Some(Token::Number(_) | Token::Name(_) | Token::Open)) {
+                left = Expr::Mul(Box::new(left), Box::new(self.power()?));
+            } else {
+                return Ok(left);
+            }
+        }
+    }
+
+    // unary := ('-' | '+') unary | power
+    fn unary(&mut self) -> Result<Expr, ParseError> {
+        if self.accept(&Token::Minus) {
+            Ok(Expr::Neg(Box::new(self.unary()?)))
+        } else if self.accept(&Token::Plus) {
+            self.unary()
+        } else {
+            self.power()
+        }
+    }
+
+    // power := atom ('^' unary)?, which is right associative
+    fn power(&mut self) -> Result<Expr, ParseError> {
+        let base: Expr = self.atom()?;
+
+        if self.accept(&Token::Caret) {
+            Ok(Expr::Pow(Box::new(base), Box::new(self.unary()?)))
+        } else {
+            Ok(base)
+        }
+    }
+
+    // atom := number | variable | constant | function '(' expression ')' | '(' expression ')'
+    fn atom(&mut self) -> Result<Expr, ParseError> {
+        let (token, position) = match self.tokens.get(self.next) {
+            Some((token, position)) => (token.clone(), *position),
+            None => return Err(ParseError::UnexpectedEnd),
+        };
+
+        match token {
+            Token::Number(x) => {
+                self.next += 1;
+                Ok(Expr::Number(x))
+            },
+            Token::Open => {
+                self.next += 1;
+                self.parenthesized()
+            },
+            Token::Name(name) => {
+                self.next += 1;
+
+                // Variables take priority, so a variable may be named e
+                if let Some(i) = self.variables.iter().position(|v| *v == name) {
+                    return Ok(Expr::Variable(i));
+                }
+
+                match (name.as_str(), Func::from_name(&name)) {
+                    (_, Some(func)) => {
+                        if !self.accept(&Token::Open) {
+                            return Err(ParseError::MissingArgument { name, position });
+                        }
+
+                        Ok(Expr::Call(func, Box::new(self.parenthesized()?)))
+                    },
+                    ("pi", None) => Ok(Expr::Constant(Constant::Pi)),
+                    ("e", None) => Ok(Expr::Constant(Constant::E)),
+                    _ => Err(ParseError::UnknownName { name, position }),
+                }
+            },
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    // The rest of a parenthesized expression, after the opening parenthesis
+    fn parenthesized(&mut self) -> Result<Expr, ParseError> {
+        let inner: Expr = self.expression()?;
+
+        if self.accept(&Token::Close) { Ok(inner) } else { Err(self.unexpected()) }
+    }
+}
+
+// Parse the source as an expression in the given variables
+pub(crate) fn parse(source: &str, variables: &[&str]) -> Result<Expr, ParseError> {
+    let mut parser: Parser = Parser { tokens: tokenize(source)?, next: 0, variables };
+    let expr: Expr = parser.expression()?;
+
+    // Anything left over, such as an unmatched closing parenthesis, is an error
+    if parser.next < parser.tokens.len() {
+        return Err(parser.unexpected());
+    }
+
+    Ok(expr)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Expression;
+
+    fn p(source: &str) -> Expr {
+        parse(source, &["x", "y"]).unwrap()
+    }
+
+    fn n(x: f64) -> Expr {
+        Expr::Number(x)
+    }
+
+    fn pow(a: Expr, b: Expr) -> Expr {
+        Expr::Pow(Box::new(a), Box::new(b))
+    }
+
+    const X: Expr = Expr::Variable(0);
+    const Y: Expr = Expr::Variable(1);
+
+    #[test]
+    fn precedence() {
+        assert_eq!(p("1 + 2 * 3^2"), n(1.0) + n(2.0) * pow(n(3.0), n(2.0)));
+        assert_eq!(p("(1 + 2) * 3"), (n(1.0) + n(2.0)) * n(3.0));
+        assert_eq!(p("2 - 3 - 4"), (n(2.0) - n(3.0)) - n(4.0));
+        assert_eq!(p("8 / 4 / 2"), (n(8.0) / n(4.0)) / n(2.0));
+        assert_eq!(p("x * y / 2"), (X * Y) / n(2.0));
+    }
+
+    #[test]
+    fn unary_minus() {
+        assert_eq!(p("-x^2"), -pow(X, n(2.0)));
+        assert_eq!(p("--x"), -(-X));
+        assert_eq!(p("+x"), X);
+        assert_eq!(p("2 * -x"), n(2.0) * -X);
+        assert_eq!(p("x - -y"), X - -Y);
+        assert_eq!(p("2^-x"), pow(n(2.0), -X));
+    }
+
+    #[test]
+    fn powers_are_right_associative() {
+        assert_eq!(p("2^3^2"), pow(n(2.0), pow(n(3.0), n(2.0))));
+        assert_eq!(p("(2^3)^2"), pow(pow(n(2.0), n(3.0)), n(2.0)));
+        assert_eq!(p("x^-y^2"), pow(X, -pow(Y, n(2.0))));
+    }
+
+    #[test]
+    fn implicit_multiplication() {
+        assert_eq!(p("2x y"), (n(2.0) * X) * Y);
+        assert_eq!(p("4x^2"), n(4.0) * pow(X, n(2.0)));
+        assert_eq!(p("x^2y"), pow(X, n(2.0)) * Y);
+        assert_eq!(p("2(x + 1)"), n(2.0) * (X + n(1.0)));
+        assert_eq!(p("2 sin(pi x)"), n(2.0) * Expr::Call(Func::Sin, Box::new(Expr::Constant(Constant::Pi) * X)));
+
+        // An e after a number is an exponent only when digits follow it
+        assert_eq!(p("1.5e3x"), n(1500.0) * X);
+        assert_eq!(p("2e-1"), n(0.2));
+        assert_eq!(p("2e"), n(2.0) * Expr::Constant(Constant::E));
+        assert_eq!(p("2e-x"), n(2.0) * Expr::Constant(Constant::E) - X);
+    }
+
+    #[test]
+    fn variables_shadow_constants() {
+        assert_eq!(parse("2e", &["e"]), Ok(n(2.0) * X));
+        assert_eq!(parse("pi", &[]), Ok(Expr::Constant(Constant::Pi)));
+    }
+
+    #[test]
+    fn error_positions() {
+        let error = |source: &str| parse(source, &["x"]).unwrap_err();
+        let token = |token: &str, position: usize| ParseError::UnexpectedToken { token: token.to_string(), position };
+
+        assert_eq!(error("sin x"), ParseError::MissingArgument { name: "sin".to_string(), position: 0 });
+        assert_eq!(error("1 + cos"), ParseError::MissingArgument { name: "cos".to_string(), position: 4 });
+        assert_eq!(error(")"), token(")", 0));
+        assert_eq!(error("x)"), token(")", 1));
+        assert_eq!(error("2..3"), token("2..3", 0));
+        assert_eq!(error("x + 2..3"), token("2..3", 4));
+        assert_eq!(error("2 * * 3"), token("*", 4));
+        assert_eq!(error("x $ 2"), ParseError::UnexpectedCharacter { character: '$', position: 2 });
+        assert_eq!(error("x + y"), ParseError::UnknownName { name: "y".to_string(), position: 4 });
+        assert_eq!(error("(x + 1"), ParseError::UnexpectedEnd);
+        assert_eq!(error("x +"), ParseError::UnexpectedEnd);
+        assert_eq!(error(""), ParseError::UnexpectedEnd);
+    }
+
+    #[test]
+    fn display_round_trip() {
+        let sources: [&str; 13] = [
+            "x^3 - 4x^2 + 3x + 1",
+            "exp(x) (1 - 2cos(x))",
+            "3/(1+x^4)",
+            "e^(-x^2) * (x^2 + 1)",
+            "t^2 sin(y) + y sin(pi t)",
+            "(x - y) - (x - y)",
+            "x / (y / 2)",
+            "x * (y / 2)",
+            "x * (y * t)",
+            "x / (y * t)",
+            "(-x)^2 - -x^2",
+            "2^3^x + (2^3)^x",
+            "-(x + y) * abs(x - 1.5e-3)",
+        ];
+
+        for source in sources {
+            let expression: Expression = Expression::parse(source, &["t", "x", "y"]).unwrap();
+            let printed: String = expression.to_string();
+            let reparsed: Expression = Expression::parse(&printed, &["t", "x", "y"]).unwrap();
+
+            assert_eq!(reparsed, expression, "{} printed as {}", source, printed);
+        }
+
+        // A product with a quotient on the right keeps its parentheses
+        assert_eq!(Expression::parse("x * (y / 2)", &["x", "y"]).unwrap().to_string(), "x * (y / 2)");
+    }
+}
@@ -0,0 +1,239 @@
+use crate::{Constant, Expr, Func};
+
+// Simplify expr from the leaves up, folding constants and applying identities
+pub(crate) fn simplify(expr: &Expr) -> Expr {
+    match expr {
+        Expr::Neg(a) => negate(simplify(a)),
+        Expr::Add(a, b) => sum(simplify(a), simplify(b)),
+        Expr::Sub(a, b) => difference(simplify(a), simplify(b)),
+        Expr::Mul(a, b) => product(simplify(a), simplify(b)),
+        Expr::Div(a, b) => quotient(simplify(a), simplify(b)),
+        Expr::Pow(a, b) => power(simplify(a), simplify(b)),
+        Expr::Call(func, a) => match (func, simplify(a)) {
+            (Func::Ln, Expr::Constant(Constant::E)) => Expr::Number(1.0),
+            (func, a) => Expr::Call(*func, Box::new(a)),
+        },
+        Expr::Number(_) | Expr::Constant(_) | Expr::Variable(_) => expr.clone(),
+    }
+}
+
+// Whether expr is a product or quotient led by a number, such as 3 * x / y
+fn coefficient(expr: &Expr) -> Option<f64> {
+    match expr {
+        Expr::Number(x) => Some(*x),
+        Expr::Mul(a, _) | Expr::Div(a, _) => coefficient(a),
+        _ => None,
+    }
+}
+
+fn negative(expr: &Expr) -> bool {
+    coefficient(expr).is_some_and(|x| x < 0.0)
+}
+
+// A power of a base with a whole exponent, so x^3 is (x, 3) and x is (x, 1)
+fn whole_power(expr: &Expr) -> (&Expr, f64) {
+    match expr {
+        Expr::Pow(a, b) => match **b {
+            Expr::Number(n) if n.fract() == 0.0 => (a, n),
+            _ => (expr, 1.0),
+        },
+        _ => (expr, 1.0),
+    }
+}
+
+// The factors of a product, in order
+fn factors(expr: Expr, out: &mut Vec<Expr>) {
+    match expr {
+        Expr::Mul(a, b) => {
+            factors(*a, out);
+            factors(*b, out);
+        },
+        a => out.push(a),
+    }
+}
+
+// The rules below take simplified operands and return a simplified result. Like the
+// identities 0 * x = 0 and x - x = 0, they assume every operand is finite, and that a
+// divisor is nonzero. Numbers are only folded into finite results, since an infinite
+// number cannot be written back as an expression
+
+// A negated product or quotient negates its leading number instead, so -(3 * x) is -3 * x
+fn negate(a: Expr) -> Expr {
+    match a {
+        Expr::Number(x) => Expr::Number(-x),
+        Expr::Neg(a) => *a,
+        Expr::Mul(a, b) if coefficient(&a).is_some() => negate(*a) * *b,
+        Expr::Div(a, b) if coefficient(&a).is_some() => negate(*a) / *b,
+        a => -a,
+    }
+}
+
+fn sum(a: Expr, b: Expr) -> Expr {
+    match (a, b) {
+        (Expr::Number(x), Expr::Number(y)) if (x + y).is_finite() => Expr::Number(x + y),
+        (Expr::Number(0.0), b) => b,
+        (a, Expr::Number(0.0)) => a,
+        // Adding a negative is subtracting
+        (a, Expr::Neg(b)) => difference(a, *b),
+        (a, b) if negative(&b) => difference(a, negate(b)),
+        (Expr::Neg(a), b) => difference(b, *a),
+        (a, b) => a + b,
+    }
+}
+
+fn difference(a: Expr, b: Expr) -> Expr {
+    match (a, b) {
+        (Expr::Number(x), Expr::Number(y)) if (x - y).is_finite() => Expr::Number(x - y),
+        (a, Expr::Number(0.0)) => a,
+        (Expr::Number(0.0), b) => negate(b),
+        // Subtracting a negative is adding
+        (a, Expr::Neg(b)) => sum(a, *b),
+        (a, b) if negative(&b) => sum(a, negate(b)),
+        (a, b) if a == b => Expr::Number(0.0),
+        (a, b) => a - b,
+    }
+}
+
+fn product(a: Expr, b: Expr) -> Expr {
+    match (a, b) {
+        (Expr::Number(x), Expr::Number(y)) if (x * y).is_finite() => Expr::Number(x * y),
+        (Expr::Number(0.0), _) | (_, Expr::Number(0.0)) => Expr::Number(0.0),
+        (Expr::Number(1.0), b) => b,
+        (a, Expr::Number(1.0)) => a,
+        (Expr::Number(-1.0), b) | (b, Expr::Number(-1.0)) => negate(b),
+        // Signs are moved outside of products
+        (Expr::Neg(a), b) => negate(product(*a, b)),
+        (a, Expr::Neg(b)) => negate(product(a, *b)),
+        // Numbers are written first, and multiplied together
+        (a, Expr::Number(y)) => product(Expr::Number(y), a),
+        // Quotients are written last, so 2 * (1 / x) is 2 / x
+        (a, Expr::Div(b, c)) => quotient(product(a, *b), *c),
+        (Expr::Div(a, b), c) => quotient(product(*a, c), *b),
+        (Expr::Number(x), Expr::Mul(b, c)) => match *b {
+            Expr::Number(y) if (x * y).is_finite() => product(Expr::Number(x * y), *c),
+            b => Expr::Number(x) * (b * *c),
+        },
+        (Expr::Mul(a, b), c) => match *a {
+            Expr::Number(x) => product(Expr::Number(x), product(*b, c)),
+            a => (a * *b) * c,
+        },
+        (a, Expr::Mul(b, c)) => match *b {
+            Expr::Number(y) => product(Expr::Number(y), product(a, *c)),
+            b => a * (b * *c),
+        },
+        (a, b) => a * b,
+    }
+}
+
+fn quotient(a: Expr, b: Expr) -> Expr {
+    match (a, b) {
+        // Only divisions with an exact result are folded, so 1 / 3 stays as it is written
+        (Expr::Number(x), Expr::Number(y)) if x % y == 0.0 => Expr::Number(x / y),
+        // 0 / 0 is left as it is written, since it is not a number
+        (Expr::Number(0.0), Expr::Number(0.0)) => Expr::Number(0.0) / Expr::Number(0.0),
+        (Expr::Number(0.0), _) => Expr::Number(0.0),
+        (a, Expr::Number(1.0)) => a,
+        // Signs are moved outside of quotients
+        (a, Expr::Number(m)) if m < 0.0 => negate(quotient(a, Expr::Number(-m))),
+        (Expr::Neg(a), b) => negate(quotient(*a, b)),
+        (a, Expr::Neg(b)) => negate(quotient(a, *b)),
+        (a, b) => cancel(a, b),
+    }
+}
+
+// Cancel a whole power of the divisor's base with the same base in the dividend, so
+// 2 * x / x^2 is 2 / x
+fn cancel(a: Expr, b: Expr) -> Expr {
+    let (base, n) = whole_power(&b);
+
+    if matches!(base, Expr::Number(_)) {
+        return a / b;
+    }
+
+    let mut top: Vec<Expr> = Vec::new();
+
+    factors(a.clone(), &mut top);
+
+    let Some(i) = top.iter().position(|f| whole_power(f).0 == base) else {
+        return a / b;
+    };
+
+    let m: f64 = whole_power(&top[i]).1;
+    let base: Expr = base.clone();
+
+    top[i] = power(base.clone(), Expr::Number((m - n).max(0.0)));
+
+    let dividend: Expr = top.into_iter().fold(Expr::Number(1.0), product);
+
+    quotient(dividend, power(base, Expr::Number((n - m).max(0.0))))
+}
+
+fn power(a: Expr, b: Expr) -> Expr {
+    match (a, b) {
+        // Only whole powers are folded, so roots stay as they are written, and 0^-1 is not
+        (Expr::Number(x), Expr::Number(y)) if y.fract() == 0.0 && x.powf(y).is_finite() => Expr::Number(x.powf(y)),
+        (_, Expr::Number(0.0)) => Expr::Number(1.0),
+        (a, Expr::Number(1.0)) => a,
+        (Expr::Number(1.0), _) => Expr::Number(1.0),
+        // (a^b)^n is a^(bn) when b and n are whole numbers. Otherwise a^(bn) can be defined
+        // where (a^b)^n is not, as x is but (x^0.5)^2 is not for negative x
+        (Expr::Pow(a, b), Expr::Number(n)) if n.fract() == 0.0 && matches!(*b, Expr::Number(m) if m.fract() == 0.0) => power(*a, product(*b, Expr::Number(n))),
+        (a, b) => Expr::Pow(Box::new(a), Box::new(b)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Expression;
+
+    fn simplified(source: &str) -> String {
+        Expression::parse(source, &["x"]).unwrap().simplify().to_string()
+    }
+
+    #[test]
+    fn identities() {
+        assert_eq!(simplified("0 + 1 * x"), "x");
+        assert_eq!(simplified("2 * (3 * x)"), "6 * x");
+        assert_eq!(simplified("x - x"), "0");
+        assert_eq!(simplified("-(3 * x)"), "-3 * x");
+        assert_eq!(simplified("6 / 3 + 1 / 3"), "2 + 1 / 3");
+    }
+
+    #[test]
+    fn zero_over_zero_is_not_folded() {
+        assert_eq!(simplified("0 / 0"), "0 / 0");
+        assert_eq!(simplified("0 / x"), "0");
+        assert!(Expression::parse("0 / 0", &[]).unwrap().simplify().compile().eval::<f64>(&[]).unwrap().is_nan());
+    }
+
+    #[test]
+    fn infinite_numbers_are_not_folded() {
+        assert_eq!(simplified("0^-1"), "0^-1");
+        assert_eq!(simplified("10^400"), "10^400");
+        assert_eq!(simplified("2^-1 * x"), "0.5 * x");
+
+        // What is printed can be read back
+        let printed: String = simplified("x + 0^-1");
+
+        assert_eq!(Expression::parse(&printed, &["x"]).unwrap().simplify().to_string(), printed);
+    }
+
+    #[test]
+    fn powers_of_powers() {
+        assert_eq!(simplified("(x^2)^3"), "x^6");
+        assert_eq!(simplified("(x^-1)^2"), "x^-2");
+
+        // Both are undefined for negative x, unlike x and x^(0.5 * x)
+        assert_eq!(simplified("(x^0.5)^2"), "(x^0.5)^2");
+        assert_eq!(simplified("(x^0.5)^x"), "(x^0.5)^x");
+    }
+
+    #[test]
+    fn quotients_cancel_powers() {
+        assert_eq!(simplified("2 * x / x^2"), "2 / x");
+        assert_eq!(simplified("x^3 / x"), "x^2");
+        assert_eq!(simplified("x / x"), "1");
+        assert_eq!(simplified("sin(x) * x^2 / x^2"), "sin(x)");
+        assert_eq!(Expression::parse("ln(x^2)", &["x"]).unwrap().derivative("x").to_string(), "2 / x");
+    }
+}
@@ -0,0 +1,172 @@
+use clap::{Parser, ValueEnum};
+use expr::Expression;
+use integr::*;
+use serde::Serialize;
+
+// The options of the program
+#[derive(Parser)]
+#[command(about = "Approximates the integral of 3/(1+x^4) on [-1, 1] with every rule")]
+struct Cli {
+    /// Print the results as JSON or CSV for other programs to read, instead of as text
+    #[arg(long, value_enum)]
+    format: Option<Format>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Json,
+    Csv,
+}
+
+// An estimate labelled with the rule that found it, for JSON output
+#[derive(Serialize)]
+struct Estimate<'a> {
+    method: &'a str,
+    #[serde(flatten)]
+    result: Quadrature,
+}
+
+// Every estimate of the integral, for JSON output
+#[derive(Serialize)]
+struct Output<'a> {
+    function: &'a str,
+    a: f64,
+    b: f64,
+    n: u16,
+    k: u8,
+    estimates: Vec<Estimate<'a>>,
+    romberg: Vec<Vec<f64>>,
+}
+
+/* 
+ * Test Functions
+ *
+fn f(x: f64) -> f64 {
+    4.0 / (1.0 + x.powf(2.0))
+}
+
+fn g(x: f64) -> f64 {
+    x.powf(4.0) + x.powf(2.0) + 1.0
+}
+
+fn h(x: f64) -> f64 {
+    std::f64::consts::E.powf(-x.powf(2.0))
+}
+
+fn j(x: f64) -> f64 {
+    x.powf(2.0).sin()
+}
+*/
+
+fn main() {
+    let cli: Cli = Cli::parse();
+
+    // The function is parsed from its identifying string, so the two always agree
+    let identifier = "3/(1+x^4)";
+    let g = Expression::parse(identifier, &["x"]).expect("the function is a valid expression").compile();
+    let g = g.bind().expect("the function is in x");
+    let f = |x: f64| g([x]);
+
+    /* 
+     * Create an array, each element contains:
+     * a reference to the Function
+     * the identifying string
+     * the interval of the estimated integral
+     * the number of subsections
+     * the the dimensions of the Romberg matrix
+     */
+    let i = Function {
+        f,
+        identifier,
+        a: -1.0,
+        b: 1.0,
+        n: 64,
+        k: 4
+    };
+
+    let t: f64 = 10_f64.powf(-6.0);
+
+    // With --format json or --format csv, print every estimate with its error for other programs to read
+    let rules = [
+        ("Left Endpoint Rule", Rule::Left),
+        ("Right Endpoint Rule", Rule::Right),
+        ("Trapezoid Rule", Rule::Trapezoid),
+        ("Midpoint Rule", Rule::Midpoint),
+        ("Simpson's Rule", Rule::Simpson),
+        ("Romberg's Method", Rule::Romberg),
+        ("Adaptive Simpson's Method", Rule::Adaptive(t)),
+    ];
+
+    match cli.format {
+        Some(Format::Json) => {
+            let estimates: Vec<Estimate> = rules.iter().map(|&(method, rule)| Estimate { method, result: quadrature(&i, rule).expect("the integrand is finite on [-1, 1]") }).collect();
+            let output = Output { function: i.identifier, a: i.a, b: i.b, n: i.n, k: i.k, estimates, romberg: romberg(&i) };
+
+            println!("{}", serde_json::to_string_pretty(&output).expect("results can always be written as JSON"));
+            return;
+        },
+        Some(Format::Csv) => {
+            println!("method,estimate,error");
+
+            for (method, rule) in rules {
+                let q: Quadrature = quadrature(&i, rule).expect("the integrand is finite on [-1, 1]");
+                println!("{},{},{}", method, q.estimate, q.error);
+            }
+            return;
+        },
+        None => {},
+    }
+
+    println!("The left endpoint estimate for the Function f(x)={} on the interval [{},{}] with n = {} is {:.11}.",
+        i.identifier,
+        i.a,
+        i.b,
+        i.n,
+        left(&i));
+
+    println!("The right endpoint estimate for the Function f(x)={} on the interval [{},{}] with n = {} is {:.11}.",
+        i.identifier,
+        i.a,
+        i.b,
+        i.n,
+        right(&i));
+
+    println!("The trapezoid endpoint estimate for the Function f(x)={} on the interval [{},{}] with n = {} is {:.11}.",
+        i.identifier,
+        i.a,
+        i.b,
+        i.n,
+        trapezoid(&i));
+
+    println!("The midpoint endpoint estimate for the Function f(x)={} on the interval [{},{}] with n = {} is {:.11}.",
+        i.identifier,
+        i.a,
+        i.b,
+        i.n,
+        midpoint(&i));
+
+    println!("The Simpson's endpoint estimate for the Function f(x)={} on the interval [{},{}] with n = {} is {:.11}.",
+        i.identifier,
+        i.a,
+        i.b,
+        i.n,
+        simpson(&i));
+
+    let r = romberg(&i);
+
+    println!("The Romberg algorithm estimate for the Function f(x)={} on the interval [{}, {}] with k = {} is {:.11}.",
+        i.identifier,
+        i.a,
+        i.b,
+        i.k,
+        r[i.k as usize - 1][i.k as usize - 1]);
+
+    println!("The Romberg matrix is:\n{}", prettify(r));
+
+    println!("The adaptive integration routine for the function f(x)={} on the interval [{}, {}] with tol = {} is {:.11}.\n",
+    i.identifier,
+    i.a,
+    i.b,
+    t,
+    adaptive(&i, t));
+}
@@ -0,0 +1,332 @@
+//! Numerical integration of a function of one variable, generic over the
+//! floating-point type.
+
+use std::fmt;
+
+use real::Real;
+use serde::{Deserialize, Serialize};
+
+/// A function to integrate on `[a, b]`, with the number of subintervals `n`
+/// and the size `k` of the Romberg matrix.
+///
+/// `f` can be any closure, so a family of functions can be integrated by
+/// capturing its parameter:
+///
+/// ```
+/// use integr::{simpson, Function};
+///
+/// for a in [1.0, 2.0, 3.0] {
+///     let i = Function { f: |x: f64| a * x * x, identifier: "ax^2", a: 0.0, b: 1.0, n: 16, k: 4 };
+///
+///     assert!((simpson(&i) - a / 3.0).abs() < 1e-12);
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Function<F, R = f64> {
+    pub f: F,
+    pub identifier: &'static str,
+    pub a: R,
+    pub b: R,
+    pub n: u16,
+    pub k: u8
+}
+
+pub fn prettify<R: Real>(v: Vec<Vec<R>>) -> String {
+    let mut result = String::new();
+
+    for i in v {
+        for j in i {
+            result.push_str(&(format!("{:.11}\t", j.to_string())));
+        }
+        result.push('\n');
+    }
+
+    String::from(&result[0..result.len() - 2])
+}
+
+/*
+ * Given: a Function struct
+ * Returns: an approximation of the area under f.f from f.a to f.b
+ * obtained using a right endpoint riemann sum
+ */
+pub fn right<R: Real, F: Fn(R) -> R>(f: &Function<F, R>) -> R {
+    let h: R = (f.b - f.a) / R::from_f64(f.n as f64);
+    let mut x = f.a + h;
+    let mut sum: R = R::from_f64(0.0);
+
+    while x <= f.b {
+        sum += (f.f)(x);
+        x += h;
+    }
+
+    sum * h
+}
+
+/*
+ * Given: a Function struct
+ * Returns: an approximation of the area under f.f from f.a to f.b
+ * obtained using a left endpoint riemann sum
+ */
+pub fn left<R: Real, F: Fn(R) -> R>(f: &Function<F, R>) -> R {
+    let h: R = (f.b - f.a) / R::from_f64(f.n as f64);
+    let mut x = f.a;
+    let mut sum: R = R::from_f64(0.0);
+
+    while x < f.b {
+        sum += (f.f)(x);
+        x += h;
+    }
+
+    sum * h
+}
+
+/*
+ * Given: a Function struct
+ * Returns: an approximation of the area under f.f from f.a to f.b
+ * obtained using a trapezoid riemann sum
+ */
+pub fn trapezoid<R: Real, F: Fn(R) -> R>(f: &Function<F, R>) -> R {
+    let h: R = (f.b - f.a) / R::from_f64(f.n as f64);
+    let mut x = f.a + h;
+    let mut sum: R = ((f.f)(f.a) + (f.f)(f.b)) / R::from_f64(2.0);
+
+    for _ in 1..f.n {
+        sum += (f.f)(x);
+        x += h;
+    }
+
+    sum * h
+}
+
+/*
+ * Given: a Function struct
+ * Returns: an approximation of the area under f.f from f.a to f.b
+ * obtained using a midpoint endpoint riemann sum
+ */
+pub fn midpoint<R: Real, F: Fn(R) -> R>(f: &Function<F, R>) -> R {
+    let h: R = (f.b - f.a) / R::from_f64(f.n as f64);
+    let mut x = f.a + h * R::from_f64(0.5);
+    let mut sum: R = R::from_f64(0.0);
+
+    while x < f.b {
+        sum += (f.f)(x);
+        x += h;
+    }
+
+    sum * h
+}
+
+/*
+ * Given: a Function struct
+ * Returns: an approximation of the area under f.f from f.a to f.b
+ * obtained using Simpson's method
+ */
+pub fn simpson<R: Real, F: Fn(R) -> R>(f: &Function<F, R>) -> R {
+    let h: R = (f.b - f.a) / R::from_f64(f.n as f64);
+    let mut x = f.a + h;
+    let mut sum4: R = R::from_f64(0.0);
+
+    // Sum up odd values of x to be multiplied by 4
+    for _ in 0..(f.n / 2) {
+        sum4 += (f.f)(x);
+        x += R::from_f64(2.0) * h;
+    }
+
+    let mut sum2: R = R::from_f64(0.0);
+    x = f.a + R::from_f64(2.0) * h;
+
+    // Sum up even values of x to be multiplied by 2
+    for _ in 1..(f.n / 2) {
+        sum2 += (f.f)(x);
+        x += R::from_f64(2.0) * h;
+    }
+
+    (h / R::from_f64(3.0)) * ((f.f)(f.a) + (f.f)(f.b) + R::from_f64(4.0) * sum4 + R::from_f64(2.0) * sum2)
+}
+
+/*
+ * Given: a Function struct with 1 <= f.k <= 15, since the
+ * last row uses 2^k subintervals
+ * Returns: A matrix containing successive
+ * iterations of richardson's method
+ */
+pub fn romberg<R: Real, F: Fn(R) -> R + Clone>(f: &Function<F, R>) -> Vec<Vec<R>> {
+    let mut r = vec![Vec::<R>::new(); f.k as usize];
+
+    // Generate the approximations with increasing values of n
+    for i in 1..(f.k + 1) {
+        r[(i - 1) as usize].push(trapezoid(&Function { n: 2_u16.pow(i as u32), ..f.clone() }));
+    }
+
+    for i in 1..f.k { // Loop through columns
+        for j in i..f.k { // Loop through rows
+            
+            // Perform richardson's method to improve accuracy
+            let power: R = R::from_f64(4_f64.powf(i as f64));
+            let new = (power * r[j as usize][(i - 1) as usize] - r[(j - 1) as usize][(i - 1) as usize]) / (power - R::from_f64(1.0));
+            r[j as usize].push(new);
+        }
+    }
+
+    r
+}
+
+// The most times adaptive Simpson's method halves an interval, which is far finer than
+// the spacing of floating-point numbers on any interval it could be asked about
+const MAX_DEPTH: u32 = 50;
+
+/*
+ * Given: a Function struct
+ * Returns: an approximation of the area under the curve
+ * using simpson's method with adaptive accuracy, or NaN if
+ * the function is not finite on the interval or the tolerance
+ * was not met after halving the interval MAX_DEPTH times
+ */
+pub fn adaptive<R: Real, F: Fn(R) -> R + Clone>(f: &Function<F, R>, t: R) -> R {
+    adaptive_error(f, t, MAX_DEPTH).map_or(R::from_f64(f64::NAN), |(estimate, _)| estimate)
+}
+
+// Adaptive Simpson's method, returning the estimate along with the sum of the
+// error estimates of the subintervals it was split into, or None if an estimate
+// is not finite or an interval still misses the tolerance after depth more halvings
+fn adaptive_error<R: Real, F: Fn(R) -> R + Clone>(f: &Function<F, R>, t: R, depth: u32) -> Option<(R, R)> {
+    let (two, four, six) = (R::from_f64(2.0), R::from_f64(4.0), R::from_f64(6.0));
+    let c = (f.a + f.b) / two;
+
+    // Simpson's estimate from a to b
+    let sab = ((f.b - f.a) / six) * ((f.f)(f.a) + four * (f.f)(c) + (f.f)(f.b));
+
+    // Simpson's estimate from a to c
+    let sac = ((c - f.a) / six) * ((f.f)(f.a) + four * (f.f)((f.a + c) / two) + (f.f)(c));
+
+    // Simpson's estimate from c to b
+    let scb = ((f.b - c) / six) * ((f.f)(c) + four * (f.f)((f.b + c) / two) + (f.f)(f.b));
+
+    let difference: R = ((sac + scb) - sab).abs();
+
+    // A NaN difference never meets the tolerance, so give up rather than recurse forever
+    if !difference.is_finite() {
+        return None;
+    }
+
+    // If we have the desired accuracy, return the estimate
+    if difference < R::from_f64(15.0) * t {
+        Some((sac + scb, difference / R::from_f64(15.0)))
+    } else if depth == 0 {
+        None
+    // Otherwise, recurse for the two halves
+    } else {
+        // The &Function { ..f.clone() } notation describes a function struct that has b = c but
+        // otherwise is a copy of f.
+        let (left, left_error) = adaptive_error(&Function { b: c, ..f.clone() }, t, depth - 1)?;
+        let (right, right_error) = adaptive_error(&Function { a: c, ..f.clone() }, t, depth - 1)?;
+
+        Some((left + right, left_error + right_error))
+    }
+}
+
+/// An approximation of an integral, with an estimate of its error.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Quadrature<R = f64> {
+    /// The approximation of the integral
+    pub estimate: R,
+    /// An estimate of the absolute error of the approximation, or NaN if there is none
+    pub error: R,
+}
+
+/// The reason [`quadrature`] could not approximate an integral.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum QuadratureError {
+    /// The interval is split into no subintervals.
+    NoSubintervals,
+    /// The Romberg matrix must have between 1 and 15 rows, since its last row uses 2^k subintervals.
+    RombergSize(u8),
+    /// The estimate is infinite or NaN, because `f` is not finite somewhere on the
+    /// interval or adaptive Simpson's method could not meet its tolerance.
+    NonFinite,
+}
+
+impl fmt::Display for QuadratureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QuadratureError::NoSubintervals => write!(f, "The interval needs at least one subinterval"),
+            QuadratureError::RombergSize(k) => write!(f, "The Romberg table needs between 1 and 15 rows, not {}", k),
+            QuadratureError::NonFinite => write!(f, "The estimate is not a finite number: f may not be finite on the interval, or the tolerance may be too small"),
+        }
+    }
+}
+
+impl std::error::Error for QuadratureError {}
+
+/// A method of approximating an integral, for [`quadrature`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Rule {
+    Left,
+    Right,
+    Trapezoid,
+    Midpoint,
+    Simpson,
+    /// Romberg's method, with a matrix of size `k`
+    Romberg,
+    /// Adaptive Simpson's method, with the given tolerance
+    Adaptive(f64),
+}
+
+/*
+ * Given: a Function struct and the rule to use
+ * Returns: the rule's approximation of the area under f.f from f.a to f.b, with an
+ * estimate of its error. The composite rules are compared with the same rule on
+ * twice as many subintervals, Romberg's method with the previous diagonal entry, and
+ * adaptive Simpson's method sums the estimates for the subintervals it was split into.
+ * An error if f.n or f.k is out of range for the rule, or the estimate is not finite
+ */
+pub fn quadrature<R: Real, F: Fn(R) -> R + Clone>(f: &Function<F, R>, rule: Rule) -> Result<Quadrature<R>, QuadratureError> {
+    let nan: R = R::from_f64(f64::NAN);
+
+    match rule {
+        Rule::Romberg if !(1..=15).contains(&f.k) => return Err(QuadratureError::RombergSize(f.k)),
+        Rule::Left | Rule::Right | Rule::Trapezoid | Rule::Midpoint | Rule::Simpson if f.n == 0 => return Err(QuadratureError::NoSubintervals),
+        _ => {},
+    }
+
+    // Richardson extrapolation: a rule of order p on 2n subintervals is about 2^p times
+    // closer, so the error on n subintervals is the difference times 2^p / (2^p - 1)
+    let composite = |method: fn(&Function<F, R>) -> R, order: i32| {
+        let estimate: R = method(f);
+        let error: R = match f.n.checked_mul(2) {
+            Some(n) => {
+                let power: R = R::from_f64(2.0).powi(order);
+
+                (method(&Function { n, ..f.clone() }) - estimate).abs() * power / (power - R::from_f64(1.0))
+            },
+            None => nan,
+        };
+
+        Quadrature { estimate, error }
+    };
+
+    let q: Quadrature<R> = match rule {
+        Rule::Left => composite(left, 1),
+        Rule::Right => composite(right, 1),
+        Rule::Trapezoid => composite(trapezoid, 2),
+        Rule::Midpoint => composite(midpoint, 2),
+        Rule::Simpson => composite(simpson, 4),
+        Rule::Romberg => {
+            let r: Vec<Vec<R>> = romberg(f);
+            let k: usize = f.k as usize;
+
+            Quadrature { estimate: r[k - 1][k - 1], error: if k > 1 { (r[k - 1][k - 1] - r[k - 2][k - 2]).abs() } else { nan } }
+        },
+        Rule::Adaptive(t) => {
+            let (estimate, error) = adaptive_error(f, R::from_f64(t), MAX_DEPTH).unwrap_or((nan, nan));
+
+            Quadrature { estimate, error }
+        },
+    };
+
+    if q.estimate.is_finite() {
+        Ok(q)
+    } else {
+        Err(QuadratureError::NonFinite)
+    }
+}
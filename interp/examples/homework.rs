@@ -23,24 +23,52 @@
  *      2 * (x - 2) / (1 - 2) * (x - 3) / (1 - 3) + 1 * (x - 3) / (2 - 3) * (x - 1) / (2 - 1) + 3 * (x - 1) / (3 - 1) * (x - 2) / (3 - 2)
  *
  * Run Project:
- *      $ cargo run
+ *      $ cargo run --example homework
  */
 
 #[macro_use]
 extern crate rulinalg;
 
+use clap::{Parser, ValueEnum};
+use expr::Expression;
+use interp::*;
 use rulinalg::vector::Vector;
-use rulinalg::matrix::{BaseMatrix, Matrix};
+use serde::Serialize;
+
+// The options of the program
+#[derive(Parser)]
+#[command(about = "Interpolates the assignment's points and function with Lagrange polynomials and a cubic spline")]
+struct Cli {
+    /// Print the results as JSON or CSV for other programs to read, instead of as text
+    #[arg(long, value_enum)]
+    format: Option<Format>,
+}
 
-// Given i, this function returns the ith chebyshev node
-// Note: i must be in [1, n], otherwise polynomial interpolation could fail
-fn c(i: f64) -> f64 {
-    2.0 * (((2.0 * i - 1.0) / 12.0) * std::f64::consts::PI).cos()
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Json,
+    Csv,
+}
+
+// Each interpolant evaluated at x, for JSON and CSV output
+#[derive(Serialize)]
+struct Values {
+    x: f64,
+    lagrange: f64,
+    chebyshev: f64,
+    spline: f64,
 }
 
-// Given x, this function returns the e^-x^2 * (x^2 + 1)
-fn f(x: f64) -> f64 {
-    std::f64::consts::E.powf(-x.powf(2.0)) * (x.powf(2.0) + 1.0)
+// The spline and every value, for JSON output
+#[derive(Serialize)]
+struct Output {
+    spline: Spline,
+    values: Vec<Values>,
+}
+
+// Given i, this function returns the ith of six chebyshev nodes on [-2, 2]
+fn c(i: f64) -> f64 {
+    chebyshev(i as usize, 6, -2.0, 2.0)
 }
 
 // Given a function and a set of x values, this function returns the
@@ -64,118 +92,39 @@ fn dd(f: fn(f64) -> f64, a: &[f64]) -> f64 {
 }
 */
 
-/* Given:
- *  - m: contains a constraint for some spline function
- *  - i: specifies the spline function
- *  - n: specifies the total number of spline functions
- * Return:
- *  - A matrix that pads the constraint with zeroes to 
- *    associate it with the correct spline function
- */
-fn pad(mut m: Matrix<f64>, i: u8, n: u8) -> Matrix<f64> {
-    let n = n - m.cols() as u8 / 4;
-
-    if i > 0 {
-        let mut a: Matrix<f64> = matrix![0.0, 0.0, 0.0, 0.0];
-
-        for _ in 0..i - 1 {
-            a = a.hcat(&matrix![0.0, 0.0, 0.0, 0.0]);
-        }
-
-        a = a.hcat(&m);
-
-        for _ in i..n - 1 {
-            a = a.hcat(&matrix![0.0, 0.0, 0.0, 0.0]);
-        }
-
-        a
-    } else {
-        for _ in 0..n - 1 {
-            m = m.hcat(&matrix![0.0, 0.0, 0.0, 0.0]); 
-        }
-
-        m
-    }
-}
-
-// Given a vector of points, sorted by x value, to interpolate, this function returns
-// a vector of coefficients for functions to build a piecewise natural cubic spline function
-fn spline_solve(p: &Vec<(f64, f64)>) -> Vector<f64> {
-    let n = p.len() as u8;
-    let mut a = pad(matrix![1.0, p[0].0, p[0].0.powf(2.0), p[0].0.powf(3.0)], 0, n);
-    let mut b = vec![p[0].1];
-
-    // Points interpolation constraint
-    for i in 1..n - 1 {
-        a = a.vcat(&pad(matrix![1.0, p[i as usize].0, p[i as usize].0.powf(2.0), p[i as usize].0.powf(3.0)], i, n));
-        b.push(p[i as usize].1);
-    }
-
-    // Continuity constraint
-    for i in 0..n - 1 {
-        a = a.vcat(&pad(matrix![1.0, p[i as usize + 1].0, p[i as usize + 1].0.powf(2.0), p[i as usize + 1].0.powf(3.0)], i, n));
-        b.push(p[i as usize + 1].1);
-    }
-
-    // Differentiability constraint
-    for i in 0..n - 2 {
-        a = a.vcat(&pad(matrix![0.0, 1.0, 2.0 * p[i as usize + 1].0, 3.0 * p[i as usize + 1].0.powf(2.0), 0.0, -1.0, -2.0 * p[i as usize + 1].0, -3.0 * p[i as usize + 1].0.powf(2.0)], i, n));
-        b.push(0.0);
-    }
-
-    // Second differentiability constraint
-    for i in 0..n - 2 {
-        a = a.vcat(&pad(matrix![0.0, 0.0, 2.0, 6.0 * p[i as usize + 1].0, 0.0, 0.0, -2.0, -6.0 * p[i as usize + 1].0], i, n));
-        b.push(0.0);
-    }
-
-    // Second derivative endpoints constraint
-    a = a.vcat(&pad(matrix![0.0, 0.0, 2.0, 6.0 * p[0].0], 0, n));
-    b.push(0.0);
+fn main() {
+    let cli: Cli = Cli::parse();
 
-    a = a.vcat(&pad(matrix![0.0, 0.0, 2.0, 6.0 * p[n as usize - 1].0], n - 2, n));
-    b.push(0.0);
+    // Given x, this function returns the e^-x^2 * (x^2 + 1)
+    let g = Expression::parse("e^(-x^2) * (x^2 + 1)", &["x"]).expect("the function is a valid expression").compile();
+    let g = g.bind().expect("the function is in x");
+    let f = |x: f64| g([x]);
 
-    // Return the vector containing values of a, b, c, and d
-    // that correspond to the appropriate spline function
-    a.solve(Vector::new(b)).unwrap()
-}
+    let mut x;
+    let mut fx;
+    let points = [1.0, 1.5, 2.25, 3.25, 4.621];
 
-/* Given:
- *  - x: the value at which to evaluate the function
- *  - v: the coefficients of the spline functions
- *  - p: the points the splines interpolate
- * Returns:
- *  - the value of the natural cubic spline at x
- */
-fn spline_evaluate(x: f64, v: &Vec<f64>, p: &Vec<(f64, f64)>) -> f64 {
-    // The range in which x lies
-    // these are the indexes of the point, so p[0], not actually 0
-    let mut a = 0;
-    let mut b = 0;
-
-    for (i, point) in p.iter().enumerate() {
-        if point.0 > p[a].0 && point.0 < x {
-            a = i;
-        } else if point.0 < p[b].0 && point.0 > x {
-            b = 1
+    // With --format json or --format csv, print the spline's coefficients and the value of each
+    // interpolant for other programs to read
+    if let Some(format) = cli.format {
+        let p = [(1.0, 2.0), (2.0, 1.0), (3.0, 3.0), (4.0, 2.0), (5.0, 3.0), (6.0, 4.0)];
+        let nodes: Vec<(f64, f64)> = (1..=6).map(|i| (c(i as f64), f(c(i as f64)))).collect();
+        let spline = Spline::new(&p).expect("the points have distinct x values");
+        let values: Vec<Values> = points.iter().map(|&x| Values { x, lagrange: lagrange(&p, x), chebyshev: lagrange(&nodes, x), spline: spline.evaluate(x) }).collect();
+
+        if format == Format::Json {
+            println!("{}", serde_json::to_string_pretty(&Output { spline, values }).expect("results can always be written as JSON"));
+        } else {
+            println!("x,lagrange,chebyshev,spline");
+
+            for v in values {
+                println!("{},{},{},{}", v.x, v.lagrange, v.chebyshev, v.spline);
+            }
         }
-    }
-
-    let mut y: f64 = 0.0;
 
-    for i in (a * 4)..(a * 4 + 4) {
-        y += v[i] * x.powf((i - (a * 4)) as f64);
+        return;
     }
 
-    y
-}
-
-fn main() {
-    let mut x;
-    let mut fx;
-    let points = [1.0, 1.5, 2.25, 3.25, 4.621];
-
     macro_rules! l {
         // One point
         // ((x, y))
@@ -249,7 +198,7 @@ fn main() {
     println!("\nNatural Cubic Spline for (1.0, 2.0), (2.0, 1.0), (3.0, 3.0), (4.0, 2.0), (5.0, 3.0), (6.0, 4.0)");
 
     let p = vec![(1.0, 2.0), (2.0, 1.0), (3.0, 3.0), (4.0, 2.0), (5.0, 3.0), (6.0, 4.0)];
-    let coefficients = spline_solve(&p).into_vec();
+    let coefficients = spline_solve(&p).unwrap();
 
     // print the cubic spline
     for (i, c) in coefficients.iter().enumerate() {
@@ -0,0 +1,275 @@
+//! Polynomial interpolation and natural cubic splines, generic over the
+//! floating-point type.
+
+use real::Real;
+use serde::{Deserialize, Serialize};
+
+// Given i, this function returns the ith of n chebyshev nodes on [a, b]
+// Note: i must be in [1, n], otherwise polynomial interpolation could fail
+pub fn chebyshev<R: Real>(i: usize, n: usize, a: R, b: R) -> R {
+    let two: R = R::from_f64(2.0);
+    let t: R = (R::from_f64(2.0 * i as f64 - 1.0) / R::from_f64(2.0 * n as f64)) * R::pi();
+
+    (a + b) / two + (b - a) / two * t.cos()
+}
+
+/* Given:
+ *  - p: the points to interpolate, with distinct x values
+ *  - x: the value at which to evaluate the polynomial
+ * Returns:
+ *  - the value at x of the lagrange polynomial through the points
+ */
+pub fn lagrange<R: Real>(p: &[(R, R)], x: R) -> R {
+    let mut sum: R = R::from_f64(0.0);
+
+    for (i, &(xi, yi)) in p.iter().enumerate() {
+        let mut term: R = yi;
+
+        for (j, &(xj, _)) in p.iter().enumerate() {
+            if i != j {
+                term = term * (x - xj) / (xi - xj);
+            }
+        }
+
+        sum += term;
+    }
+
+    sum
+}
+
+/* Given:
+ *  - block: contains a constraint for some spline functions
+ *  - i: specifies the first spline function it constrains
+ *  - n: specifies the total number of points
+ * Return:
+ *  - A row that pads the constraint with zeroes to
+ *    associate it with the correct spline functions
+ */
+fn pad<R: Real>(block: &[R], i: usize, n: usize) -> Vec<R> {
+    let mut row: Vec<R> = vec![R::from_f64(0.0); 4 * (n - 1)];
+
+    row[4 * i..4 * i + block.len()].copy_from_slice(block);
+    row
+}
+
+// Solves the linear system a x = b by LU decomposition with partial pivoting,
+// returning None if a is singular to working precision
+fn solve<R: Real>(mut a: Vec<Vec<R>>, b: Vec<R>) -> Option<Vec<R>> {
+    let n: usize = b.len();
+    let mut order: Vec<usize> = (0..n).collect();
+
+    // A pivot no larger than the rounding error of elimination, relative to the infinity norm
+    // of a, means a is singular, however large or small its entries are
+    let norm: R = a.iter().fold(R::from_f64(0.0), |m, row| m.max(row.iter().fold(R::from_f64(0.0), |sum, &x| sum + x.abs())));
+    let tolerance: R = R::from_f64(n as f64) * R::epsilon() * norm;
+
+    for k in 0..n {
+        // Use the largest remaining entry in column k as the pivot
+        let pivot: usize = (k + 1..n).fold(k, |m, i| if a[i][k].abs() > a[m][k].abs() { i } else { m });
+
+        if a[pivot][k].abs() <= tolerance {
+            return None;
+        }
+
+        a.swap(k, pivot);
+        order.swap(k, pivot);
+
+        let (top, rest) = a.split_at_mut(k + 1);
+        let pivot_row: &Vec<R> = &top[k];
+
+        for row in rest {
+            let l: R = row[k] / pivot_row[k];
+
+            row[k] = l;
+
+            for (x, u) in row[k + 1..].iter_mut().zip(&pivot_row[k + 1..]) {
+                *x -= l * *u;
+            }
+        }
+    }
+
+    let mut x: Vec<R> = order.iter().map(|&i| b[i]).collect();
+
+    // Forward substitution with the unit lower triangle
+    for i in 1..n {
+        let sum: R = (0..i).fold(R::from_f64(0.0), |sum, j| sum + a[i][j] * x[j]);
+
+        x[i] -= sum;
+    }
+
+    // Back substitution with the upper triangle
+    for i in (0..n).rev() {
+        let sum: R = (i + 1..n).rev().fold(R::from_f64(0.0), |sum, j| sum + a[i][j] * x[j]);
+
+        x[i] = (x[i] - sum) / a[i][i];
+    }
+
+    Some(x)
+}
+
+// Given a slice of points, sorted by x value, to interpolate, this function returns
+// a vector of coefficients for functions to build a piecewise natural cubic spline function,
+// or None if there are fewer than two points or the x values are not distinct
+pub fn spline_solve<R: Real>(p: &[(R, R)]) -> Option<Vec<R>> {
+    let n = p.len();
+
+    if n < 2 {
+        return None;
+    }
+
+    let (zero, one, two, six) = (R::from_f64(0.0), R::from_f64(1.0), R::from_f64(2.0), R::from_f64(6.0));
+    let mut a = vec![pad(&[one, p[0].0, p[0].0.powi(2), p[0].0.powi(3)], 0, n)];
+    let mut b = vec![p[0].1];
+
+    // Points interpolation constraint
+    for (i, point) in p.iter().enumerate().take(n - 1).skip(1) {
+        a.push(pad(&[one, point.0, point.0.powi(2), point.0.powi(3)], i, n));
+        b.push(point.1);
+    }
+
+    // Continuity constraint
+    for i in 0..n - 1 {
+        a.push(pad(&[one, p[i + 1].0, p[i + 1].0.powi(2), p[i + 1].0.powi(3)], i, n));
+        b.push(p[i + 1].1);
+    }
+
+    // Differentiability constraint
+    for i in 0..n - 2 {
+        let x: R = p[i + 1].0;
+
+        a.push(pad(&[zero, one, two * x, R::from_f64(3.0) * x.powi(2), zero, -one, -two * x, R::from_f64(-3.0) * x.powi(2)], i, n));
+        b.push(zero);
+    }
+
+    // Second differentiability constraint
+    for i in 0..n - 2 {
+        let x: R = p[i + 1].0;
+
+        a.push(pad(&[zero, zero, two, six * x, zero, zero, -two, -six * x], i, n));
+        b.push(zero);
+    }
+
+    // Second derivative endpoints constraint
+    a.push(pad(&[zero, zero, two, six * p[0].0], 0, n));
+    b.push(zero);
+
+    a.push(pad(&[zero, zero, two, six * p[n - 1].0], n - 2, n));
+    b.push(zero);
+
+    // Return the vector containing values of a, b, c, and d
+    // that correspond to the appropriate spline function
+    solve(a, b)
+}
+
+/* Given:
+ *  - x: the value at which to evaluate the function
+ *  - v: the coefficients of the spline functions
+ *  - p: the points the splines interpolate
+ * Returns:
+ *  - the value of the natural cubic spline at x
+ */
+pub fn spline_evaluate<R: Real>(x: R, v: &[R], p: &[(R, R)]) -> R {
+    // The spline function whose interval x lies in, extending the first
+    // and last functions beyond the points
+    let mut a = 0;
+
+    for (i, point) in p.iter().enumerate() {
+        if point.0 > p[a].0 && point.0 < x {
+            a = i;
+        }
+    }
+
+    let a = a.min(p.len() - 2);
+    let mut y: R = R::from_f64(0.0);
+
+    for (i, c) in v[a * 4..a * 4 + 4].iter().enumerate() {
+        y += *c * x.powf(R::from_f64(i as f64));
+    }
+
+    y
+}
+
+/// One cubic of a natural cubic spline,
+/// `S(x) = c[0] + c[1] x + c[2] x^2 + c[3] x^3` on `[a, b]`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SplinePiece<R = f64> {
+    pub a: R,
+    pub b: R,
+    pub coefficients: [R; 4],
+}
+
+/// A natural cubic spline, with one cubic for each interval between the points it interpolates.
+///
+/// ```
+/// use interp::Spline;
+///
+/// let s: Spline = Spline::new(&[(1.0, 2.0), (2.0, 1.0), (3.0, 3.0)]).unwrap();
+///
+/// assert_eq!(s.pieces.len(), 2);
+/// assert!((s.evaluate(2.0) - 1.0).abs() < 1e-12);
+///
+/// // A spline needs at least two points
+/// assert!(Spline::<f64>::new(&[(1.0, 2.0)]).is_none());
+/// assert!(Spline::<f64>::new(&[(1.0, 2.0), (3.0, 1.0)]).is_some());
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Spline<R = f64> {
+    pub pieces: Vec<SplinePiece<R>>,
+}
+
+impl<R: Real> Spline<R> {
+    /// The natural cubic spline through the points, which must be sorted by
+    /// x value, or `None` if there are fewer than two points or the x values
+    /// are not distinct.
+    pub fn new(p: &[(R, R)]) -> Option<Spline<R>> {
+        let v: Vec<R> = spline_solve(p)?;
+        let pieces = p.windows(2).zip(v.chunks(4)).map(|(w, c)| SplinePiece { a: w[0].0, b: w[1].0, coefficients: [c[0], c[1], c[2], c[3]] });
+
+        Some(Spline { pieces: pieces.collect() })
+    }
+
+    /// The value of the spline at `x`, extending the first and last cubics beyond the points.
+    pub fn evaluate(&self, x: R) -> R {
+        let piece: &SplinePiece<R> = &self.pieces[self.pieces.iter().rposition(|piece| piece.a < x).unwrap_or(0)];
+        let mut y: R = R::from_f64(0.0);
+
+        for (i, c) in piece.coefficients.iter().enumerate() {
+            y += *c * x.powf(R::from_f64(i as f64));
+        }
+
+        y
+    }
+
+    /// Formats the spline as CSV, with a header and one row per cubic.
+    pub fn to_csv(&self) -> String {
+        let mut csv: String = String::from("a,b,c0,c1,c2,c3\n");
+
+        for piece in &self.pieces {
+            let [c0, c1, c2, c3] = piece.coefficients;
+
+            csv += &format!("{},{},{},{},{},{}\n", piece.a, piece.b, c0, c1, c2, c3);
+        }
+
+        csv
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solve_is_independent_of_scale() {
+        // Every entry is far below epsilon, but the system is well conditioned
+        let a: Vec<Vec<f64>> = vec![vec![2e-20, 1e-20], vec![1e-20, 3e-20]];
+        let x: Vec<f64> = solve(a, vec![4e-20, 7e-20]).unwrap();
+
+        assert!((x[0] - 1.0).abs() < 1e-15 && (x[1] - 2.0).abs() < 1e-15);
+
+        // The second row is a third of the first, so elimination leaves only rounding error,
+        // which is far larger than epsilon when the entries are large
+        let a: Vec<Vec<f64>> = vec![vec![1.1e20, 2.4e20], vec![1.1e20 / 3.0, 8e19]];
+
+        assert_eq!(solve(a, vec![1.0, 2.0]), None);
+    }
+}
@@ -0,0 +1,114 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{integrate, interpolate, json, ode, root, Format};
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// A TOML or JSON file of [[problem]] tables, each with a kind of root, integrate, interpolate or ode and the options of that subcommand
+    file: PathBuf,
+}
+
+// One problem of a job file, with the same fields as the options of its subcommand
+#[derive(Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+enum Problem {
+    Root(root::Args),
+    Integrate(integrate::Args),
+    Interpolate(interpolate::Args),
+    Ode(ode::Args),
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Job {
+    problem: Vec<Problem>,
+}
+
+/// One line of the comparison table: a problem, the method run on it, and
+/// what it found or why it failed.
+#[derive(Serialize)]
+pub struct Row {
+    kind: &'static str,
+    problem: String,
+    method: &'static str,
+    result: Option<f64>,
+    detail: String,
+}
+
+impl Row {
+    /// A row for a result with a note on how it was found, or for the reason the method failed.
+    pub fn new(kind: &'static str, problem: String, method: &'static str, outcome: Result<(f64, String), String>) -> Row {
+        let (result, detail): (Option<f64>, String) = match outcome {
+            Ok((x, note)) => (Some(x), note),
+            Err(e) => (None, e),
+        };
+
+        Row { kind, problem, method, result, detail }
+    }
+}
+
+// Quote a CSV field if it contains a comma, a quote or a line break
+fn field(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+pub fn run(args: Args, format: Format) -> Result<(), String> {
+    let source: String = std::fs::read_to_string(&args.file).map_err(|e| format!("Could not read {}: {}", args.file.display(), e))?;
+
+    // JSON files are read as JSON, and anything else as TOML
+    let job: Result<Job, String> = match args.file.extension().and_then(|e| e.to_str()) {
+        Some("json") => serde_json::from_str(&source).map_err(|e| e.to_string()),
+        _ => toml::from_str(&source).map_err(|e| e.to_string()),
+    };
+    let job: Job = job.map_err(|e| format!("Could not read the problems in {}: {}", args.file.display(), e))?;
+
+    let rows: Vec<Row> = job.problem.into_iter().flat_map(|problem| match problem {
+        Problem::Root(args) => root::rows(args),
+        Problem::Integrate(args) => integrate::rows(args),
+        Problem::Interpolate(args) => interpolate::rows(args),
+        Problem::Ode(args) => ode::rows(args),
+    }).collect();
+
+    match format {
+        Format::Text => {
+            let cells: Vec<[String; 4]> = rows.iter().map(|r| [
+                r.problem.clone(),
+                r.method.to_string(),
+                r.result.map_or("failed".to_string(), |x| x.to_string()),
+                r.detail.clone(),
+            ]).collect();
+            let header: [String; 4] = ["Problem", "Method", "Result", "Detail"].map(String::from);
+
+            // Each column is as wide as its widest cell, counting characters rather than bytes
+            let mut widths: [usize; 4] = [0; 4];
+
+            for row in std::iter::once(&header).chain(&cells) {
+                for (width, cell) in widths.iter_mut().zip(row) {
+                    *width = (*width).max(cell.chars().count());
+                }
+            }
+
+            for row in std::iter::once(&header).chain(&cells) {
+                let line: Vec<String> = row.iter().zip(widths).map(|(cell, width)| format!("{:<1$}", cell, width)).collect();
+
+                println!("{}", line.join("  ").trim_end());
+            }
+        },
+        Format::Json => println!("{}", json(&rows)),
+        Format::Csv => {
+            println!("kind,problem,method,result,detail");
+
+            for r in &rows {
+                println!("{},{},{},{},{}", r.kind, field(&r.problem), field(r.method), r.result.map_or(String::new(), |x| x.to_string()), field(&r.detail));
+            }
+        },
+    }
+
+    Ok(())
+}
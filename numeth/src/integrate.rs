@@ -0,0 +1,142 @@
+use clap::ValueEnum;
+use integr::*;
+use serde::Deserialize;
+
+use crate::batch::Row;
+use crate::{json, Format, Named};
+
+#[derive(Clone, Copy, Default, ValueEnum, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Method {
+    Left,
+    Right,
+    Trapezoid,
+    Midpoint,
+    #[default]
+    Simpson,
+    Romberg,
+    Adaptive,
+}
+
+#[derive(clap::Args, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Args {
+    /// The function, in x
+    function: String,
+    #[arg(short, long, value_enum, default_value_t = Method::default())]
+    #[serde(default)]
+    method: Method,
+    /// The lower limit of integration
+    #[arg(short, long, allow_hyphen_values = true)]
+    a: f64,
+    /// The upper limit of integration
+    #[arg(short, long, allow_hyphen_values = true)]
+    b: f64,
+    /// The number of subintervals
+    #[arg(short, default_value_t = n())]
+    #[serde(default = "n")]
+    n: u16,
+    /// The number of rows of the Romberg table
+    #[arg(short, default_value_t = k())]
+    #[serde(default = "k")]
+    k: u8,
+    /// The tolerance of adaptive Simpson's method
+    #[arg(short, long, default_value_t = tolerance())]
+    #[serde(default = "tolerance")]
+    tolerance: f64,
+}
+
+// The defaults of the options, shared by the command line and batch files
+fn n() -> u16 {
+    64
+}
+
+fn k() -> u8 {
+    4
+}
+
+fn tolerance() -> f64 {
+    1e-6
+}
+
+impl Args {
+    /// The problem, as a batch's comparison table describes it.
+    pub fn problem(&self) -> String {
+        format!("{} on [{}, {}]", self.function, self.a, self.b)
+    }
+
+    /// The name of the method, as the other programs print it.
+    pub fn method(&self) -> &'static str {
+        name(self.method)
+    }
+}
+
+// The name of a method, as the other programs print it
+fn name(method: Method) -> &'static str {
+    match method {
+        Method::Left => "Left Endpoint Rule",
+        Method::Right => "Right Endpoint Rule",
+        Method::Trapezoid => "Trapezoid Rule",
+        Method::Midpoint => "Midpoint Rule",
+        Method::Simpson => "Simpson's Rule",
+        Method::Romberg => "Romberg's Method",
+        Method::Adaptive => "Adaptive Simpson's Method",
+    }
+}
+
+// Estimate the integral with the chosen rule, returning the rule's parameter as text
+fn solve(args: &Args) -> Result<(String, Quadrature), String> {
+    let expression = crate::parse(&args.function, &["x"])?;
+    let g = expression.compile();
+    let g = g.bind().map_err(|e| e.to_string())?;
+
+    let i = Function {
+        f: |x: f64| g([x]),
+        // The identifier is only a label, and the output names the function itself
+        identifier: "f",
+        a: args.a,
+        b: args.b,
+        n: args.n,
+        k: args.k,
+    };
+
+    let n: String = format!("n = {}", i.n);
+    let (parameter, rule): (String, Rule) = match args.method {
+        Method::Left => (n, Rule::Left),
+        Method::Right => (n, Rule::Right),
+        Method::Trapezoid => (n, Rule::Trapezoid),
+        Method::Midpoint => (n, Rule::Midpoint),
+        Method::Simpson => (n, Rule::Simpson),
+        Method::Romberg => (format!("k = {}", i.k), Rule::Romberg),
+        Method::Adaptive => (format!("tol = {}", args.tolerance), Rule::Adaptive(args.tolerance)),
+    };
+
+    let q: Quadrature = quadrature(&i, rule).map_err(|e| format!("{}: {}", name(args.method), e))?;
+
+    Ok((parameter, q))
+}
+
+pub fn run(args: Args, format: Format) -> Result<(), String> {
+    let (parameter, q): (String, Quadrature) = solve(&args)?;
+
+    match format {
+        Format::Text => println!(
+            "{}: the integral of f(x)={} on the interval [{}, {}] with {} is {}, with an error of about {}.",
+            name(args.method), args.function, args.a, args.b, parameter, q.estimate, q.error
+        ),
+        Format::Json => println!("{}", json(&Named { method: name(args.method), result: q })),
+        Format::Csv => {
+            println!("method,estimate,error");
+            println!("{},{},{}", name(args.method), q.estimate, q.error);
+        },
+    }
+
+    Ok(())
+}
+
+/// The integral as a row of a batch's comparison table.
+pub fn rows(args: Args) -> Vec<Row> {
+    let outcome = solve(&args).map(|(parameter, q)| (q.estimate, format!("{}, error about {:.1e}", parameter, q.error)));
+
+    vec![Row::new("integrate", args.problem(), args.method(), outcome)]
+}
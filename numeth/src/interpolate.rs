@@ -0,0 +1,193 @@
+use clap::ValueEnum;
+use interp::*;
+use serde::{Deserialize, Serialize};
+
+use crate::batch::Row;
+use crate::{json, Format};
+
+#[derive(Clone, Copy, Default, ValueEnum, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Method {
+    #[default]
+    Lagrange,
+    Spline,
+}
+
+#[derive(clap::Args, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Args {
+    #[arg(short, long, value_enum, default_value_t = Method::default())]
+    #[serde(default)]
+    method: Method,
+    /// The points to interpolate, as "x,y x,y ..."
+    #[arg(short, long, value_delimiter = ' ', value_parser = point, allow_hyphen_values = true, required_unless_present = "function")]
+    #[serde(default)]
+    points: Vec<(f64, f64)>,
+    /// A function in x to interpolate at Chebyshev nodes instead of points
+    #[arg(short, long, conflicts_with = "points", requires_all = ["a", "b"])]
+    function: Option<String>,
+    /// The lower end of the interval the nodes are on
+    #[arg(short, allow_hyphen_values = true)]
+    a: Option<f64>,
+    /// The upper end of the interval the nodes are on
+    #[arg(short, allow_hyphen_values = true)]
+    b: Option<f64>,
+    /// The number of Chebyshev nodes
+    #[arg(short, long, default_value_t = nodes())]
+    #[serde(default = "nodes")]
+    nodes: usize,
+    /// The values of x to evaluate the interpolant at, without which a spline's coefficients are printed
+    #[arg(long, num_args = 1.., allow_negative_numbers = true)]
+    #[serde(default)]
+    at: Vec<f64>,
+}
+
+// The default number of nodes, shared by the command line and batch files
+fn nodes() -> usize {
+    6
+}
+
+// A point, or the interpolant evaluated at x
+#[derive(Serialize)]
+struct Value {
+    x: f64,
+    y: f64,
+}
+
+// Everything computed, for JSON output
+#[derive(Serialize)]
+struct Interpolation {
+    method: &'static str,
+    // The name of the interpolant, as in P(x) or S(x)
+    #[serde(skip)]
+    label: &'static str,
+    points: Vec<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    spline: Option<Spline>,
+    values: Vec<Value>,
+}
+
+impl Args {
+    /// The problem, as a batch's comparison table describes it.
+    pub fn problem(&self) -> String {
+        match (&self.function, self.a, self.b) {
+            (Some(function), Some(a), Some(b)) => format!("{} at {} Chebyshev nodes on [{}, {}]", function, self.nodes, a, b),
+            _ => format!("{} points", self.points.len()),
+        }
+    }
+
+    /// The name of the method, as the other programs print it.
+    pub fn method(&self) -> &'static str {
+        name(self.method)
+    }
+}
+
+// The name of a method, as the other programs print it
+fn name(method: Method) -> &'static str {
+    match method {
+        Method::Lagrange => "Lagrange Polynomial",
+        Method::Spline => "Natural Cubic Spline",
+    }
+}
+
+// Parse a point given as x,y
+fn point(s: &str) -> Result<(f64, f64), String> {
+    let (x, y) = s.split_once(',').ok_or(format!("\"{}\" is not a point x,y", s))?;
+    let number = |n: &str| n.trim().parse::<f64>().map_err(|e| format!("\"{}\" is not a number: {}", n, e));
+
+    Ok((number(x)?, number(y)?))
+}
+
+// Interpolate the points, or the function at Chebyshev nodes, with the chosen method
+fn solve(args: Args) -> Result<Interpolation, String> {
+    let mut p: Vec<(f64, f64)> = match (&args.function, args.a, args.b) {
+        (Some(function), Some(a), Some(b)) => {
+            let g = crate::parse(function, &["x"])?.compile();
+            let g = g.bind().map_err(|e| e.to_string())?;
+
+            (1..=args.nodes).map(|i| chebyshev(i, args.nodes, a, b)).map(|x| (x, g([x]))).collect()
+        },
+        (Some(_), _, _) => return Err("Interpolating a function needs the interval a, b the nodes are on".to_string()),
+        _ => args.points,
+    };
+
+    // The spline needs the points in order, and the Chebyshev nodes are in decreasing order
+    p.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    if p.windows(2).any(|w| w[0].0 == w[1].0) {
+        return Err("The x values of the points must be distinct".to_string());
+    }
+
+    let (label, spline): (&str, Option<Spline>) = match args.method {
+        Method::Lagrange => {
+            if p.is_empty() {
+                return Err("Lagrange interpolation needs at least one point".to_string());
+            }
+
+            if args.at.is_empty() {
+                return Err("Give the values of x to evaluate the polynomial at with --at".to_string());
+            }
+
+            ("P", None)
+        },
+        Method::Spline => {
+            if p.len() < 2 {
+                return Err("A cubic spline needs at least two points".to_string());
+            }
+
+            ("S", Some(Spline::new(&p).ok_or("The spline's system of equations is singular")?))
+        },
+    };
+
+    let values: Vec<Value> = args.at.iter().map(|&x| Value { x, y: spline.as_ref().map_or_else(|| lagrange(&p, x), |s| s.evaluate(x)) }).collect();
+
+    Ok(Interpolation { method: name(args.method), label, points: p.iter().map(|&(x, y)| Value { x, y }).collect(), spline, values })
+}
+
+pub fn run(args: Args, format: Format) -> Result<(), String> {
+    let interpolation: Interpolation = solve(args)?;
+    let Interpolation { label, spline, values, .. } = &interpolation;
+
+    match format {
+        Format::Text => {
+            if let (Some(s), true) = (spline, values.is_empty()) {
+                // Write each term with its sign, as in S(x) = 3 + 0.5x - 2.25x^2
+                let term = |c: f64, power: &str| if c >= 0.0 { format!(" + {}{}", c, power) } else { format!(" - {}{}", -c, power) };
+
+                for (i, piece) in s.pieces.iter().enumerate() {
+                    let [c0, c1, c2, c3] = piece.coefficients;
+
+                    println!("S{}(x) = {}{}{}{} on [{}, {}]", i, c0, term(c1, "x"), term(c2, "x^2"), term(c3, "x^3"), piece.a, piece.b);
+                }
+            }
+
+            for v in values {
+                println!("{}({}) = {}", label, v.x, v.y);
+            }
+        },
+        Format::Json => println!("{}", json(&interpolation)),
+        Format::Csv => match (spline, values.is_empty()) {
+            (Some(s), true) => print!("{}", s.to_csv()),
+            _ => {
+                println!("x,y");
+
+                for v in values {
+                    println!("{},{}", v.x, v.y);
+                }
+            },
+        },
+    }
+
+    Ok(())
+}
+
+/// The interpolant at each value of x as a row of a batch's comparison table.
+pub fn rows(args: Args) -> Vec<Row> {
+    let (problem, method): (String, &str) = (args.problem(), args.method());
+
+    match solve(args) {
+        Ok(Interpolation { values, .. }) if values.is_empty() => vec![Row::new("interpolate", problem, method, Err("Give the values of x to evaluate the interpolant at with at".to_string()))],
+        Ok(Interpolation { label, values, .. }) => values.iter().map(|v| Row::new("interpolate", problem.clone(), method, Ok((v.y, format!("{}({})", label, v.x))))).collect(),
+        Err(e) => vec![Row::new("interpolate", problem, method, Err(e))],
+    }
+}
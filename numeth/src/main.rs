@@ -0,0 +1,92 @@
+/*
+ * Written By: Alex Jackson (asj18a@acu.edu)
+ *
+ * A command-line interface to every numerical method:
+ *  - numeth root: approximating a root of f(x)
+ *  - numeth integrate: approximating the integral of f(x) on [a, b]
+ *  - numeth interpolate: interpolating points, or a function at Chebyshev nodes
+ *  - numeth ode: approximating the solution of y' = f(t, y)
+ *  - numeth batch: running every problem listed in a TOML or JSON file, and comparing the results
+ *
+ * Functions are given as expressions, such as "x^3 - 4x^2 + 3x + 1". Results are printed
+ * as text, or with --format json or --format csv for other programs to read.
+ *
+ * Running the program:
+ *  - Ensure you have the latest rust toolchain
+ *  - Run the following command: cargo run -p numeth -- --help
+ *
+ *  */
+
+mod batch;
+mod integrate;
+mod interpolate;
+mod ode;
+mod root;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use expr::Expression;
+use serde::Serialize;
+
+#[derive(Parser)]
+#[command(name = "numeth", about = "Numerical methods for roots, integrals, interpolation and differential equations")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// How to print the results
+    #[arg(long, global = true, value_enum, default_value = "text")]
+    format: Format,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+// A result labelled with the method that found it, for JSON output
+#[derive(Serialize)]
+struct Named<'a, T> {
+    method: &'a str,
+    #[serde(flatten)]
+    result: T,
+}
+
+fn json(value: &impl Serialize) -> String {
+    serde_json::to_string_pretty(value).expect("results can always be written as JSON")
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Approximate a root of f(x)
+    Root(root::Args),
+    /// Approximate the integral of f(x) over [a, b]
+    Integrate(integrate::Args),
+    /// Interpolate points, or a function sampled at Chebyshev nodes
+    Interpolate(interpolate::Args),
+    /// Approximate y(t) for the initial value problem y' = f(t, y)
+    Ode(ode::Args),
+    /// Run every problem listed in a TOML or JSON file, and print a table comparing the results
+    Batch(batch::Args),
+}
+
+// Parse a function given on the command line, naming it in the error
+fn parse(source: &str, variables: &[&str]) -> Result<Expression, String> {
+    Expression::parse(source, variables).map_err(|e| format!("Could not parse \"{}\": {}", source, e))
+}
+
+fn main() {
+    let cli: Cli = Cli::parse();
+    let result: Result<(), String> = match cli.command {
+        Command::Root(args) => root::run(args, cli.format),
+        Command::Integrate(args) => integrate::run(args, cli.format),
+        Command::Interpolate(args) => interpolate::run(args, cli.format),
+        Command::Ode(args) => ode::run(args, cli.format),
+        Command::Batch(args) => batch::run(args, cli.format),
+    };
+
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,128 @@
+use clap::ValueEnum;
+use differ::*;
+use serde::Deserialize;
+
+use crate::batch::Row;
+use crate::{json, Format, Named};
+
+#[derive(Clone, Copy, Default, ValueEnum, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Method {
+    Euler,
+    Pc,
+    #[default]
+    Rkf45,
+}
+
+#[derive(clap::Args, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Args {
+    /// The function f(t, y) in y' = f(t, y)
+    function: String,
+    #[arg(short, long, value_enum, default_value_t = Method::default())]
+    #[serde(default)]
+    method: Method,
+    /// The initial value of t
+    #[arg(long, allow_hyphen_values = true)]
+    t0: f64,
+    /// The initial value of y, at t0
+    #[arg(long, allow_hyphen_values = true)]
+    y0: f64,
+    /// The value of t to approximate y at
+    #[arg(short, long, allow_hyphen_values = true)]
+    t: f64,
+    /// The step size, which is the initial step size for RKF45
+    #[arg(long, default_value_t = h())]
+    #[serde(default = "h")]
+    h: f64,
+    /// The tolerance of RKF45
+    #[arg(short, long, default_value_t = error())]
+    #[serde(default = "error")]
+    error: f64,
+    /// The weight of the predictor-corrector method, where 0.5 is Heun's method
+    #[arg(short, long, default_value_t = c())]
+    #[serde(default = "c")]
+    c: f64,
+}
+
+// The defaults of the options, shared by the command line and batch files
+fn h() -> f64 {
+    0.1
+}
+
+fn error() -> f64 {
+    1e-6
+}
+
+fn c() -> f64 {
+    0.5
+}
+
+impl Args {
+    /// The problem, as a batch's comparison table describes it.
+    pub fn problem(&self) -> String {
+        format!("y' = {}, y({}) = {}", self.function, self.t0, self.y0)
+    }
+
+    /// The name of the method, as the other programs print it.
+    pub fn method(&self) -> &'static str {
+        name(self.method)
+    }
+}
+
+// The name of a method, as the other programs print it
+fn name(method: Method) -> &'static str {
+    match method {
+        Method::Euler => "Euler's Method",
+        Method::Pc => "Predictor-Corrector Method",
+        Method::Rkf45 => "RKF45",
+    }
+}
+
+// Approximate the solution with the chosen method
+fn solve(args: &Args) -> Result<Trajectory, String> {
+    let g = crate::parse(&args.function, &["t", "y"])?.compile();
+    let g = g.bind().map_err(|e| e.to_string())?;
+    let f = |t: f64, y: f64| g([t, y]);
+
+    if args.h <= 0.0 {
+        return Err("The step size must be positive".to_string());
+    }
+
+    // The methods only step forward in t
+    if args.t < args.t0 {
+        return Err("The value of t cannot be before t0".to_string());
+    }
+
+    // The predictor takes a step of h / (2(1 - c))
+    if args.c == 1.0 {
+        return Err("The weight of the predictor-corrector method cannot be 1".to_string());
+    }
+
+    let trajectory: Result<Trajectory, OdeError> = match args.method {
+        Method::Euler => euler_trajectory(f, args.t0, args.t, args.y0, args.h),
+        Method::Pc => pc_trajectory(f, args.t0, args.t, args.y0, args.h, args.c),
+        Method::Rkf45 => rkf45_trajectory(f, args.t0, args.t, args.y0, args.h, args.error),
+    };
+
+    trajectory.map_err(|e| format!("{}: {}", name(args.method), e))
+}
+
+pub fn run(args: Args, format: Format) -> Result<(), String> {
+    let trajectory: Trajectory = solve(&args)?;
+
+    match format {
+        Format::Text => println!("y({}) = {} with h = {}", trajectory.last().t, trajectory.last().y, args.h),
+        Format::Json => println!("{}", json(&Named { method: name(args.method), result: &trajectory })),
+        Format::Csv => print!("{}", trajectory.to_csv()),
+    }
+
+    Ok(())
+}
+
+/// y(t) as a row of a batch's comparison table.
+pub fn rows(args: Args) -> Vec<Row> {
+    let outcome = solve(&args).map(|trajectory| (trajectory.last().y, format!("y({}), {} steps", trajectory.last().t, trajectory.points.len() - 1)));
+
+    vec![Row::new("ode", args.problem(), args.method(), outcome)]
+}
@@ -0,0 +1,197 @@
+use approx::*;
+use clap::ValueEnum;
+use serde::Deserialize;
+
+use crate::batch::Row;
+use crate::{json, Format, Named};
+
+#[derive(Clone, Copy, Default, ValueEnum, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Method {
+    Bisection,
+    Newton,
+    Halley,
+    Secant,
+    Steffensen,
+    Muller,
+    Invquad,
+    Falsepos,
+    Illinois,
+    Pegasus,
+    AndersonBjorck,
+    Ridders,
+    #[default]
+    Brent,
+}
+
+#[derive(clap::Args, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Args {
+    /// The function, in x
+    function: String,
+    #[arg(short, long, value_enum, default_value_t = Method::default())]
+    #[serde(default)]
+    method: Method,
+    /// An interval containing the root, as a,b
+    #[arg(short, long, value_delimiter = ',', num_args = 1, allow_hyphen_values = true)]
+    interval: Option<Vec<f64>>,
+    /// A starting point, from which a bracketing method searches for an interval
+    #[arg(short, long, allow_hyphen_values = true)]
+    guess: Option<f64>,
+    /// Absolute tolerance on the change in x
+    #[arg(long, default_value_t = x_tol())]
+    #[serde(default = "x_tol")]
+    x_tol: f64,
+    /// Tolerance on |f(x)|
+    #[arg(long, default_value_t = f_tol())]
+    #[serde(default = "f_tol")]
+    f_tol: f64,
+    #[arg(long, default_value_t = max_iterations())]
+    #[serde(default = "max_iterations")]
+    max_iterations: usize,
+    /// Print every iteration after the result, or only the iterations with --format csv
+    #[arg(long)]
+    #[serde(default)]
+    trace: bool,
+}
+
+// The defaults of the options, shared by the command line and batch files
+fn x_tol() -> f64 {
+    SolverOptions::default().x_abs_tol
+}
+
+fn f_tol() -> f64 {
+    SolverOptions::default().f_tol
+}
+
+fn max_iterations() -> usize {
+    SolverOptions::default().max_iterations
+}
+
+impl Args {
+    /// The problem, as a batch's comparison table describes it.
+    pub fn problem(&self) -> String {
+        self.function.clone()
+    }
+
+    /// The name of the method, as the other programs print it.
+    pub fn method(&self) -> &'static str {
+        name(self.method)
+    }
+}
+
+// The name of a method, as the other programs print it
+fn name(method: Method) -> &'static str {
+    match method {
+        Method::Bisection => "Bisection Method",
+        Method::Newton => "Newton's Method",
+        Method::Halley => "Halley's Method",
+        Method::Secant => "Secant Method",
+        Method::Steffensen => "Steffensen's Method",
+        Method::Muller => "Muller's Method",
+        Method::Invquad => "Inverse Quadratic Iteration",
+        Method::Falsepos => "False Position Method",
+        Method::Illinois => "Illinois Method",
+        Method::Pegasus => "Pegasus Method",
+        Method::AndersonBjorck => "Anderson-Björck Method",
+        Method::Ridders => "Ridders' Method",
+        Method::Brent => "Brent's Method",
+    }
+}
+
+// Find the root with the chosen method
+fn solve(args: &Args) -> Result<Root, String> {
+    let expression = crate::parse(&args.function, &["x"])?;
+    let derivative = expression.derivative("x");
+    let second_derivative = derivative.derivative("x");
+    let (f, ff, fff) = (expression.compile(), derivative.compile(), second_derivative.compile());
+    let (f, ff, fff) = (f.bind().map_err(|e| e.to_string())?, ff.bind().map_err(|e| e.to_string())?, fff.bind().map_err(|e| e.to_string())?);
+    let (f, ff, fff) = (|x: f64| f([x]), |x: f64| ff([x]), |x: f64| fff([x]));
+
+    let opts = SolverOptions {
+        x_abs_tol: args.x_tol,
+        f_tol: args.f_tol,
+        max_iterations: args.max_iterations,
+        trace: args.trace,
+        ..SolverOptions::default()
+    };
+
+    let interval: Option<(f64, f64)> = match args.interval.as_deref() {
+        Some(&[a, b]) => Some((a, b)),
+        Some(_) => return Err("The interval must be given as two numbers, a,b".to_string()),
+        None => None,
+    };
+
+    // Open methods start from the guess, or the middle of the interval
+    let guess: Result<f64, String> = match (args.guess, interval) {
+        (Some(x), _) => Ok(x),
+        (None, Some((a, b))) => Ok((a + b) / 2.0),
+        (None, None) => Err(format!("{} needs a starting point, given with --guess or --interval", name(args.method))),
+    };
+    let points: Result<(f64, f64), String> = interval.ok_or(format!("{} needs two starting points, given with --interval", name(args.method)));
+
+    // Bracketing methods search for an interval from the guess if none was given
+    let bracketed = |method: &dyn BracketedMethod<_>| match (interval, args.guess) {
+        (Some(x), _) => Ok(method(f, x, &opts)),
+        (None, Some(x)) => Ok(bracket_and_solve(f, (x, x), method, &opts)),
+        (None, None) => Err(format!("{} needs an interval, or a starting point to search from", name(args.method))),
+    };
+
+    let result: Result<Root, RootError> = match args.method {
+        Method::Bisection => bracketed(&bisection)?,
+        Method::Falsepos => bracketed(&falsepos)?,
+        Method::Illinois => bracketed(&illinois)?,
+        Method::Pegasus => bracketed(&pegasus)?,
+        Method::AndersonBjorck => bracketed(&anderson_bjorck)?,
+        Method::Ridders => bracketed(&ridders)?,
+        Method::Brent => bracketed(&brent)?,
+        Method::Newton => newton(f, ff, guess?, &opts),
+        Method::Halley => halley(f, ff, fff, guess?, &opts),
+        Method::Steffensen => steffensen(f, guess?, &opts),
+        Method::Secant => secant(f, points?, &opts),
+        Method::Muller => {
+            let (a, b) = points?;
+            muller(f, (a, (a + b) / 2.0, b), &opts)
+        },
+        Method::Invquad => {
+            let (a, b) = points?;
+            invquad(f, (a, (a + b) / 2.0, b), &opts)
+        },
+    };
+
+    result.map_err(|e| format!("{}: {}", name(args.method), e))
+}
+
+pub fn run(args: Args, format: Format) -> Result<(), String> {
+    let root: Root = solve(&args)?;
+
+    match (format, &root.trace) {
+        (Format::Text, _) => {
+            println!("{}: x = {} ({} iterations, {} f and {} f' evaluations)", name(args.method), root.x, root.iterations, root.f_evaluations, root.df_evaluations);
+
+            if let Some(trace) = &root.trace {
+                print!("{}", trace.to_csv());
+            }
+        },
+        (Format::Json, _) => println!("{}", json(&Named { method: name(args.method), result: &root })),
+        (Format::Csv, Some(trace)) => print!("{}", trace.to_csv()),
+        (Format::Csv, None) => {
+            println!("method,x,iterations,f_evaluations,df_evaluations");
+            println!("{},{},{},{},{}", name(args.method), root.x, root.iterations, root.f_evaluations, root.df_evaluations);
+        },
+    }
+
+    Ok(())
+}
+
+/// The root as a row of a batch's comparison table, labelled with where the method started.
+pub fn rows(args: Args) -> Vec<Row> {
+    let start: String = match (&args.interval, args.guess) {
+        (Some(interval), _) => format!("[{}]", interval.iter().map(|x| x.to_string()).collect::<Vec<String>>().join(", ")),
+        (None, Some(x)) => format!("x0 = {}", x),
+        (None, None) => String::new(),
+    };
+    let outcome = solve(&args).map(|root| (root.x, format!("{}, {} iterations", start, root.iterations)));
+
+    vec![Row::new("root", args.problem(), args.method(), outcome)]
+}
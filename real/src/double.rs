@@ -0,0 +1,576 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::Real;
+
+/// A double-double number: the unevaluated sum `hi + lo` of two `f64`s,
+/// where `lo` is at most half a unit in the last place of `hi`.
+///
+/// Carries about 32 significant digits (a unit roundoff of `2^-104`) with
+/// the range of an `f64`, which is enough to check how much of a result
+/// computed in `f64` is rounding error. Arithmetic and square roots are
+/// accurate to nearly full precision; the elementary functions lose a few
+/// more bits to argument reduction.
+///
+/// ```
+/// use real::{DoubleDouble, Real};
+///
+/// let third: DoubleDouble = DoubleDouble::from(1.0) / DoubleDouble::from(3.0);
+///
+/// assert_eq!(format!("{:.30}", third), "0.333333333333333333333333333333");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct DoubleDouble {
+    hi: f64,
+    lo: f64,
+}
+
+const LN_2: DoubleDouble = DoubleDouble { hi: std::f64::consts::LN_2, lo: 2.319_046_813_846_299_6e-17 };
+const PI: DoubleDouble = DoubleDouble { hi: std::f64::consts::PI, lo: 1.224_646_799_147_353_2e-16 };
+const TAU: DoubleDouble = DoubleDouble { hi: std::f64::consts::TAU, lo: 2.449_293_598_294_706_4e-16 };
+const FRAC_PI_2: DoubleDouble = DoubleDouble { hi: std::f64::consts::FRAC_PI_2, lo: 6.123_233_995_736_766e-17 };
+
+// The number of significant digits printed when no precision is given
+const DIGITS: usize = 32;
+
+// a + b exactly, as the rounded sum and its rounding error
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s: f64 = a + b;
+    let v: f64 = s - a;
+
+    (s, (a - (s - v)) + (b - v))
+}
+
+// a + b exactly, where |a| >= |b|
+fn quick_two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s: f64 = a + b;
+
+    (s, b - (s - a))
+}
+
+// a * b exactly, as the rounded product and its rounding error
+fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    let p: f64 = a * b;
+
+    (p, a.mul_add(b, -p))
+}
+
+impl DoubleDouble {
+    /// Creates the number `hi + lo`.
+    pub fn new(hi: f64, lo: f64) -> Self {
+        let (hi, lo) = two_sum(hi, lo);
+
+        DoubleDouble::normalized(hi, lo)
+    }
+
+    /// The leading `f64`, which is the number rounded to an `f64`.
+    pub fn hi(self) -> f64 {
+        self.hi
+    }
+
+    /// The trailing `f64`, the rounding error of [`hi`](DoubleDouble::hi).
+    pub fn lo(self) -> f64 {
+        self.lo
+    }
+
+    // Combine hi and a small correction lo, keeping infinities and NaN in hi
+    fn normalized(hi: f64, lo: f64) -> Self {
+        if !hi.is_finite() {
+            return DoubleDouble { hi, lo: 0.0 };
+        }
+
+        let (hi, lo) = quick_two_sum(hi, lo);
+
+        DoubleDouble { hi, lo }
+    }
+
+    // Multiply by an f64
+    fn scale(self, b: f64) -> Self {
+        let (p, e) = two_prod(self.hi, b);
+
+        DoubleDouble::normalized(p, e + self.lo * b)
+    }
+
+    // Multiply by 2^k, which is exact
+    fn ldexp(self, k: i32) -> Self {
+        // Scale in two halves so neither factor overflows
+        let (a, b) = (2_f64.powi(k / 2), 2_f64.powi(k - k / 2));
+
+        DoubleDouble { hi: self.hi * a * b, lo: self.lo * a * b }
+    }
+
+    // sin and cos of a number that has already been reduced to |x| <= π/4, using their Taylor series
+    fn sin_cos_reduced(self) -> (Self, Self) {
+        let x2: DoubleDouble = self * self;
+        let (mut sin, mut sin_term) = (self, self);
+        let (mut cos, mut cos_term) = (DoubleDouble::from(1.0), DoubleDouble::from(1.0));
+        let mut n: f64 = 1.0;
+
+        while sin_term.hi.abs() > 1e-34 || cos_term.hi.abs() > 1e-34 {
+            cos_term = -cos_term * x2 / DoubleDouble::from(n * (n + 1.0));
+            sin_term = -sin_term * x2 / DoubleDouble::from((n + 1.0) * (n + 2.0));
+            cos += cos_term;
+            sin += sin_term;
+            n += 2.0;
+        }
+
+        (sin, cos)
+    }
+
+    // sin and cos, reducing the argument to within π/4 of a multiple of π/2
+    fn sin_cos(self) -> (Self, Self) {
+        if !self.is_finite() {
+            return (DoubleDouble::from(f64::NAN), DoubleDouble::from(f64::NAN));
+        }
+
+        let x: DoubleDouble = self - TAU.scale((self.hi / TAU.hi).round());
+        let quadrant: f64 = (x.hi / FRAC_PI_2.hi).round();
+        let (sin, cos) = (x - FRAC_PI_2.scale(quadrant)).sin_cos_reduced();
+
+        match quadrant as i32 {
+            1 | -3 => (cos, -sin),
+            2 | -2 => (-sin, -cos),
+            -1 | 3 => (-cos, sin),
+            _ => (sin, cos),
+        }
+    }
+
+    // The exponent of the first significant decimal digit, and |self| scaled by it into [1, 10)
+    fn decimal_exponent(self) -> (i32, DoubleDouble) {
+        let mut exponent: i32 = self.hi.abs().log10().floor() as i32;
+        let mut y: DoubleDouble = self.abs() / DoubleDouble::from(10.0).powi(exponent);
+
+        // log10 can be off by one near powers of ten
+        if y.hi >= 10.0 {
+            y /= DoubleDouble::from(10.0);
+            exponent += 1;
+        } else if y.hi < 1.0 {
+            y *= DoubleDouble::from(10.0);
+            exponent -= 1;
+        }
+
+        (exponent, y)
+    }
+
+    // The first n significant decimal digits, rounded, and the exponent of the first digit
+    fn digits(self, n: usize) -> (Vec<u8>, i32) {
+        let (mut exponent, mut y) = self.decimal_exponent();
+        let mut digits: Vec<u8> = Vec::new();
+
+        for _ in 0..=n {
+            let d: DoubleDouble = y.floor();
+
+            digits.push(d.hi.clamp(0.0, 9.0) as u8);
+            y = (y - d).scale(10.0);
+        }
+
+        // Round on the extra digit, carrying into the digits before it
+        if digits.pop().unwrap_or(0) >= 5 {
+            let mut i: usize = digits.len();
+
+            loop {
+                if i == 0 {
+                    digits.insert(0, 1);
+                    digits.pop();
+                    exponent += 1;
+                    break;
+                }
+
+                i -= 1;
+
+                if digits[i] == 9 {
+                    digits[i] = 0;
+                } else {
+                    digits[i] += 1;
+                    break;
+                }
+            }
+        }
+
+        (digits, exponent)
+    }
+}
+
+impl From<f64> for DoubleDouble {
+    fn from(x: f64) -> Self {
+        DoubleDouble { hi: x, lo: 0.0 }
+    }
+}
+
+impl Add for DoubleDouble {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let (s, e) = two_sum(self.hi, rhs.hi);
+
+        if !s.is_finite() {
+            return DoubleDouble::from(s);
+        }
+
+        let (t, f) = two_sum(self.lo, rhs.lo);
+        let (s, e) = quick_two_sum(s, e + t);
+
+        DoubleDouble::normalized(s, e + f)
+    }
+}
+
+impl Sub for DoubleDouble {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl Mul for DoubleDouble {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let (p, e) = two_prod(self.hi, rhs.hi);
+
+        if !p.is_finite() {
+            return DoubleDouble::from(p);
+        }
+
+        DoubleDouble::normalized(p, e + (self.hi * rhs.lo + self.lo * rhs.hi))
+    }
+}
+
+impl Div for DoubleDouble {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        let q1: f64 = self.hi / rhs.hi;
+
+        if !q1.is_finite() || q1 == 0.0 {
+            return DoubleDouble::from(q1);
+        }
+
+        // Long division, one f64 of the quotient at a time
+        let r: DoubleDouble = self - rhs.scale(q1);
+        let q2: f64 = r.hi / rhs.hi;
+        let r: DoubleDouble = r - rhs.scale(q2);
+        let q3: f64 = r.hi / rhs.hi;
+
+        DoubleDouble::normalized(q1, q2) + DoubleDouble::from(q3)
+    }
+}
+
+impl Neg for DoubleDouble {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        DoubleDouble { hi: -self.hi, lo: -self.lo }
+    }
+}
+
+impl AddAssign for DoubleDouble {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for DoubleDouble {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for DoubleDouble {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for DoubleDouble {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl Real for DoubleDouble {
+    fn from_f64(x: f64) -> Self {
+        DoubleDouble::from(x)
+    }
+
+    fn to_f64(self) -> f64 {
+        self.hi + self.lo
+    }
+
+    fn epsilon() -> Self {
+        DoubleDouble::from(2_f64.powi(-104))
+    }
+
+    fn max_value() -> Self {
+        DoubleDouble::from(f64::MAX)
+    }
+
+    fn pi() -> Self {
+        PI
+    }
+
+    fn abs(self) -> Self {
+        if self.hi < 0.0 { -self } else { self }
+    }
+
+    fn signum(self) -> Self {
+        DoubleDouble::from(self.hi.signum())
+    }
+
+    fn copysign(self, sign: Self) -> Self {
+        if self.hi.is_sign_negative() == sign.hi.is_sign_negative() { self } else { -self }
+    }
+
+    fn max(self, other: Self) -> Self {
+        if self.is_nan() || other > self { other } else { self }
+    }
+
+    fn min(self, other: Self) -> Self {
+        if self.is_nan() || other < self { other } else { self }
+    }
+
+    fn floor(self) -> Self {
+        let hi: f64 = self.hi.floor();
+
+        if hi == self.hi {
+            DoubleDouble::normalized(hi, self.lo.floor())
+        } else {
+            DoubleDouble::from(hi)
+        }
+    }
+
+    fn round(self) -> Self {
+        let hi: f64 = self.hi.round();
+
+        if hi == self.hi {
+            DoubleDouble::normalized(hi, self.lo.round())
+        } else if (hi - self.hi).abs() == 0.5 {
+            // hi is exactly halfway, so lo decides which way to round
+            let down: f64 = self.hi.floor();
+
+            DoubleDouble::from(if self.lo > 0.0 { down + 1.0 } else if self.lo < 0.0 { down } else { hi })
+        } else {
+            DoubleDouble::from(hi)
+        }
+    }
+
+    fn sqrt(self) -> Self {
+        if self.hi <= 0.0 || !self.is_finite() {
+            return DoubleDouble::from(self.hi.sqrt());
+        }
+
+        // One Newton step from the f64 square root doubles the number of correct digits
+        let x: DoubleDouble = DoubleDouble::from(self.hi.sqrt());
+
+        x + (self - x * x) / x.scale(2.0)
+    }
+
+    fn powi(self, n: i32) -> Self {
+        let mut result: DoubleDouble = DoubleDouble::from(1.0);
+        let mut base: DoubleDouble = self;
+        let mut k: u32 = n.unsigned_abs();
+
+        // Square and multiply
+        while k > 0 {
+            if k & 1 == 1 {
+                result *= base;
+            }
+
+            base *= base;
+            k >>= 1;
+        }
+
+        if n < 0 { DoubleDouble::from(1.0) / result } else { result }
+    }
+
+    fn powf(self, n: Self) -> Self {
+        if n.floor() == n && n.hi.abs() < i32::MAX as f64 {
+            self.powi(n.hi as i32)
+        } else if self.hi == 0.0 {
+            DoubleDouble::from(if n.hi > 0.0 { 0.0 } else { f64::INFINITY })
+        } else {
+            (n * self.ln()).exp()
+        }
+    }
+
+    fn exp(self) -> Self {
+        if self.hi > 709.8 {
+            return DoubleDouble::from(f64::INFINITY);
+        } else if self.hi < -745.2 {
+            return DoubleDouble::from(0.0);
+        } else if self.is_nan() {
+            return self;
+        }
+
+        // exp(x) = 2^k exp(r) with |r| <= ln(2) / 2, and r is divided by 2^10 again for the series
+        let k: f64 = (self.hi / LN_2.hi).round();
+        let r: DoubleDouble = (self - LN_2.scale(k)).ldexp(-10);
+
+        // Sum the series for exp(r) - 1, which keeps its relative precision while squaring
+        let mut s: DoubleDouble = r;
+        let mut term: DoubleDouble = r;
+        let mut n: f64 = 2.0;
+
+        while term.hi.abs() > 1e-36 {
+            term = term * r / DoubleDouble::from(n);
+            s += term;
+            n += 1.0;
+        }
+
+        // (1 + s)^2 - 1 = 2s + s^2, applied once for each halving of r
+        for _ in 0..10 {
+            s = s.scale(2.0) + s * s;
+        }
+
+        (s + DoubleDouble::from(1.0)).ldexp(k as i32)
+    }
+
+    fn ln(self) -> Self {
+        if self.hi <= 0.0 || !self.is_finite() {
+            return DoubleDouble::from(self.hi.ln());
+        }
+
+        // One Newton step on exp(y) = x from the f64 logarithm
+        let y: DoubleDouble = DoubleDouble::from(self.hi.ln());
+
+        y + self * (-y).exp() - DoubleDouble::from(1.0)
+    }
+
+    fn sin(self) -> Self {
+        self.sin_cos().0
+    }
+
+    fn cos(self) -> Self {
+        self.sin_cos().1
+    }
+
+    fn tan(self) -> Self {
+        let (sin, cos) = self.sin_cos();
+
+        sin / cos
+    }
+
+    fn is_finite(self) -> bool {
+        self.hi.is_finite()
+    }
+
+    fn is_nan(self) -> bool {
+        self.hi.is_nan()
+    }
+}
+
+impl fmt::Display for DoubleDouble {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !self.hi.is_finite() || self.hi == 0.0 {
+            return fmt::Display::fmt(&self.hi, f);
+        }
+
+        // With a precision, print that many decimal places, and otherwise every significant digit
+        let (digits, exponent, places) = match f.precision() {
+            Some(places) => {
+                let (exponent, y) = self.decimal_exponent();
+                let n: i32 = exponent + 1 + places as i32;
+
+                if n > 0 {
+                    let (digits, exponent) = self.digits(n as usize);
+
+                    (digits, exponent, places)
+                } else {
+                    // Every digit is past the last place, so the number rounds to zero or, when its
+                    // first digit is just past the last place and at least 5, to one in the last place
+                    let digits: Vec<u8> = if n == 0 && y >= DoubleDouble::from(5.0) { vec![1] } else { Vec::new() };
+
+                    (digits, -(places as i32), places)
+                }
+            },
+            None => {
+                let (mut digits, exponent) = self.digits(DIGITS);
+
+                while digits.len() > 1 && digits.last() == Some(&0) {
+                    digits.pop();
+                }
+
+                let places: usize = (digits.len() as i32 - 1 - exponent).max(0) as usize;
+
+                (digits, exponent, places)
+            },
+        };
+
+        let digit = |i: i32| if i >= 0 && (i as usize) < digits.len() { (b'0' + digits[i as usize]) as char } else { '0' };
+
+        // Digit i has place value 10^(exponent - i)
+        let mut body: String = if exponent >= 0 { (0..=exponent).map(digit).collect() } else { String::from("0") };
+
+        if places > 0 {
+            body.push('.');
+            body.extend((1..=places as i32).map(|p| digit(exponent + p)));
+        }
+
+        f.pad_integral(self.hi > 0.0, "", &body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Whether x is within a relative tolerance of the double-double hi + lo
+    fn close(x: DoubleDouble, hi: f64, lo: f64, tolerance: f64) -> bool {
+        let expected: DoubleDouble = DoubleDouble::new(hi, lo);
+
+        ((x - expected) / expected).abs().hi <= tolerance
+    }
+
+    #[test]
+    fn arithmetic() {
+        let one: DoubleDouble = DoubleDouble::from(1.0);
+
+        assert!(close(one / DoubleDouble::from(3.0), 0.3333333333333333, 1.850371707708594e-17, 1e-31));
+        assert!(close(one / DoubleDouble::from(7.0), 0.14285714285714285, 7.93016446160826e-18, 1e-31));
+        assert!(close(DoubleDouble::from(2.0).sqrt(), std::f64::consts::SQRT_2, -9.667293313452913e-17, 1e-31));
+
+        // 0.1 + 0.2 - 0.3 is not zero in f64, but is exactly the sum of their rounding errors here
+        let sum: DoubleDouble = DoubleDouble::from(0.1) + DoubleDouble::from(0.2) - DoubleDouble::from(0.3);
+
+        assert_eq!(sum.hi(), 2.7755575615628914e-17);
+    }
+
+    #[test]
+    fn elementary_functions() {
+        let one: DoubleDouble = DoubleDouble::from(1.0);
+
+        assert!(close(DoubleDouble::pi(), std::f64::consts::PI, 1.2246467991473532e-16, 1e-31));
+        assert!(close(one.exp(), std::f64::consts::E, 1.4456468917292502e-16, 1e-30));
+        assert!(close(DoubleDouble::from(-10.0).exp(), 4.5399929762484854e-05, -2.637554055327531e-21, 1e-30));
+        assert!(close(DoubleDouble::from(2.0).ln(), std::f64::consts::LN_2, 2.3190468138462996e-17, 1e-30));
+        assert!(close(DoubleDouble::from(10.0).ln(), std::f64::consts::LN_10, -2.1707562233822494e-16, 1e-30));
+        assert!(close(one.sin(), 0.8414709848078965, 1.776845092935536e-18, 1e-30));
+        assert!(close(one.cos(), 0.5403023058681398, -4.760954612604417e-17, 1e-30));
+        assert!(close(DoubleDouble::from(100.0).sin(), -0.5063656411097588, -3.050947053792115e-18, 1e-30));
+        assert!(close(DoubleDouble::from(100.0).cos(), 0.8623188722876839, 4.334809858136501e-17, 1e-30));
+    }
+
+    #[test]
+    fn display() {
+        let one: DoubleDouble = DoubleDouble::from(1.0);
+
+        assert_eq!(format!("{}", DoubleDouble::from(0.1)), "0.10000000000000000555111512312578");
+        assert_eq!(format!("{}", one / DoubleDouble::from(7.0)), "0.14285714285714285714285714285714");
+        assert_eq!(format!("{}", DoubleDouble::from(-2.5)), "-2.5");
+        assert_eq!(format!("{}", DoubleDouble::from(1e20)), "100000000000000000000");
+        assert_eq!(format!("{:.30}", DoubleDouble::from(2.0).sqrt()), "1.414213562373095048801688724210");
+        assert_eq!(format!("{:.3}", DoubleDouble::from(9.9996)), "10.000");
+        assert_eq!(format!("{:>8.2}", DoubleDouble::from(2.5)), "    2.50");
+    }
+
+    #[test]
+    fn display_below_the_last_place() {
+        assert_eq!(format!("{:.8}", DoubleDouble::from(6e-9)), "0.00000001");
+        assert_eq!(format!("{:.8}", DoubleDouble::from(-6e-9)), "-0.00000001");
+        assert_eq!(format!("{:.8}", DoubleDouble::from(9.99e-9)), "0.00000001");
+        assert_eq!(format!("{:.8}", DoubleDouble::from(4e-9)), "0.00000000");
+        assert_eq!(format!("{:.8}", DoubleDouble::from(4e-10)), "0.00000000");
+        assert_eq!(format!("{:.0}", DoubleDouble::from(0.6)), "1");
+        assert_eq!(format!("{:.0}", DoubleDouble::from(0.4)), "0");
+    }
+}
@@ -0,0 +1,104 @@
+/*
+ * Written By: Alex Jackson (asj18a@acu.edu)
+ *
+ * The floating-point operations shared by every numerical method:
+ *  - The Real trait, implemented for f32 and f64
+ *  - DoubleDouble, an extended precision type with about 32 significant digits
+ *
+ *  */
+
+//! A trait for the real number types the numerical methods run on.
+//!
+//! Every method is written once against [`Real`], so it can run in `f32`
+//! for speed, `f64` as usual, or [`DoubleDouble`] to verify a result with
+//! twice the precision.
+
+mod double;
+
+use std::fmt::{Debug, Display};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+pub use crate::double::DoubleDouble;
+
+/// A real floating-point number type.
+pub trait Real:
+    Copy
+    + PartialOrd
+    + Debug
+    + Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+{
+    /// Converts from an `f64`, rounding if the type is less precise.
+    fn from_f64(x: f64) -> Self;
+
+    /// Converts to the nearest `f64`.
+    fn to_f64(self) -> f64;
+
+    /// The difference between 1 and the next larger representable number.
+    fn epsilon() -> Self;
+
+    /// The largest finite value.
+    fn max_value() -> Self;
+
+    /// Archimedes' constant, π.
+    fn pi() -> Self;
+
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+    fn copysign(self, sign: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+    fn min(self, other: Self) -> Self;
+    fn floor(self) -> Self;
+    fn round(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn is_finite(self) -> bool;
+    fn is_nan(self) -> bool;
+}
+
+// Implement Real for a primitive float type by forwarding to its inherent methods
+macro_rules! primitive {
+    ($t:ident) => {
+        impl Real for $t {
+            fn from_f64(x: f64) -> Self { x as $t }
+            fn to_f64(self) -> f64 { self as f64 }
+            fn epsilon() -> Self { $t::EPSILON }
+            fn max_value() -> Self { $t::MAX }
+            fn pi() -> Self { std::$t::consts::PI }
+            fn abs(self) -> Self { $t::abs(self) }
+            fn signum(self) -> Self { $t::signum(self) }
+            fn copysign(self, sign: Self) -> Self { $t::copysign(self, sign) }
+            fn max(self, other: Self) -> Self { $t::max(self, other) }
+            fn min(self, other: Self) -> Self { $t::min(self, other) }
+            fn floor(self) -> Self { $t::floor(self) }
+            fn round(self) -> Self { $t::round(self) }
+            fn sqrt(self) -> Self { $t::sqrt(self) }
+            fn powi(self, n: i32) -> Self { $t::powi(self, n) }
+            fn powf(self, n: Self) -> Self { $t::powf(self, n) }
+            fn exp(self) -> Self { $t::exp(self) }
+            fn ln(self) -> Self { $t::ln(self) }
+            fn sin(self) -> Self { $t::sin(self) }
+            fn cos(self) -> Self { $t::cos(self) }
+            fn tan(self) -> Self { $t::tan(self) }
+            fn is_finite(self) -> bool { $t::is_finite(self) }
+            fn is_nan(self) -> bool { $t::is_nan(self) }
+        }
+    };
+}
+
+primitive!(f32);
+primitive!(f64);